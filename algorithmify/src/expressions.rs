@@ -1,48 +1,100 @@
+use anyhow::anyhow;
+
 use crate::interpreter::context::Context;
 
 pub use self::{
+    assertion::Assertion,
     block::Block,
+    cast::{Cast, CastType},
+    closure::{Closure, ClosureCall},
     conditions::Condition,
+    enumeration::Enum,
     float::Float,
+    format::{Format, FormatArg, Print},
     functions::{Function, FunctionBuilder, FunctionCall, FunctionParams},
     integer::Integer,
     loops::Loop,
     method_call::MethodCall,
-    operation::Operation,
+    operation::{Operation, UnaryOperation},
+    pattern::Pattern,
+    range::Range,
+    reference::FieldAccessExpression,
     reference::IndexedAccessExpression,
     reference::Reference,
     statements::Statement,
+    structure::Struct,
+    types::Type,
 };
 
+/// Executes an expression from a function that returns `anyhow::Result<Expression>`,
+/// returning early with any control flow signal (`break`, `continue` or `return`)
+/// that the expression yields so that it reaches the loop or function that handles it.
+macro_rules! execute_or_propagate {
+    ($expression:expr, $context:expr) => {
+        match $expression.execute($context)? {
+            result if result.is_control_flow() => return Ok(result),
+            result => result,
+        }
+    };
+}
+
+pub mod assertion;
 pub mod block;
+pub mod cast;
+pub mod closure;
 pub mod conditions;
+pub mod enumeration;
 pub mod float;
+pub mod format;
 pub mod functions;
 pub mod integer;
 pub mod loops;
 pub mod method_call;
 pub mod operation;
+pub mod pattern;
+pub mod range;
 pub mod reference;
 pub mod statements;
+pub mod structure;
+pub mod types;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression {
     Unit,
-    Break,
+    Break(Option<String>, Box<Expression>),
+    Continue(Option<String>),
+    Return(Box<Expression>),
+    Try(Box<Expression>),
     Vector(Vec<Expression>),
+    /// A `vec![value; count]` whose count is only known at runtime.
+    VectorRepetition(Box<Expression>, Box<Expression>),
+    Tuple(Vec<Expression>),
+    Range(Box<Range>),
+    Struct(Struct),
+    Enum(Enum),
+    Optional(Option<Box<Expression>>),
+    Result(Result<Box<Expression>, Box<Expression>>),
     Reference(Reference),
     IndexedAccessExpression(IndexedAccessExpression),
+    FieldAccessExpression(FieldAccessExpression),
     Integer(Integer),
     Float(Float),
     Char(char),
     String(String),
     Bool(bool),
     Operation(Box<Operation>),
+    UnaryOperation(Box<UnaryOperation>),
+    Cast(Box<Cast>),
     Condition(Box<Condition>),
     Loop(Box<Loop>),
     FunctionCall(FunctionCall),
+    Closure(Box<Closure>),
+    ClosureCall(ClosureCall),
     Block(Box<Block>),
     MethodCall(MethodCall),
+    /// A `format!`, which evaluates to a string.
+    Format(Box<Format>),
+    Print(Box<Print>),
 }
 
 impl Expression {
@@ -50,16 +102,70 @@ impl Expression {
         match self {
             Self::Reference(reference) => reference.execute(context),
             Self::IndexedAccessExpression(expression) => expression.execute(context),
+            Self::FieldAccessExpression(expression) => expression.execute(context),
             Self::Operation(operation) => operation.execute(context),
+            Self::UnaryOperation(operation) => operation.execute(context),
+            Self::Cast(cast) => cast.execute(context),
             Self::Loop(loop_instance) => loop_instance.execute(context),
             Self::Condition(condition) => condition.execute(context),
             Self::Block(block) => block.execute(context),
             Self::FunctionCall(function_call) => function_call.execute(context),
+            Self::Closure(closure) => closure.execute(context),
+            Self::ClosureCall(closure_call) => closure_call.execute(context),
             Self::MethodCall(method_call) => method_call.execute(context),
+            Self::Format(format) => format.execute(context),
+            Self::Print(print) => print.execute(context),
+            Self::Break(label, expression) => Ok(Self::Break(
+                label.clone(),
+                Box::new(execute_or_propagate!(expression, context)),
+            )),
+            Self::Return(expression) => Ok(Self::Return(Box::new(execute_or_propagate!(
+                expression, context
+            )))),
+            Self::Try(expression) => execute_try(execute_or_propagate!(expression, context)),
+            Self::Vector(expressions) => {
+                let mut vector = Vec::with_capacity(expressions.len());
+                for expression in expressions {
+                    vector.push(execute_or_propagate!(expression, context));
+                }
+                Ok(Self::Vector(vector))
+            }
+            Self::VectorRepetition(value, count) => {
+                let value = execute_or_propagate!(value, context);
+                match execute_or_propagate!(count, context) {
+                    Self::Integer(count) => Ok(Self::Vector(vec![value; count.as_usize()])),
+                    count => Err(anyhow!("Invalid repetition count '{:?}'", count)),
+                }
+            }
+            Self::Tuple(expressions) => {
+                let mut tuple = Vec::with_capacity(expressions.len());
+                for expression in expressions {
+                    tuple.push(execute_or_propagate!(expression, context));
+                }
+                Ok(Self::Tuple(tuple))
+            }
+            Self::Range(range) => range.execute(context),
+            Self::Struct(structure) => structure.execute(context),
+            Self::Enum(enumeration) => enumeration.execute(context),
+            Self::Optional(Some(expression)) => Ok(Self::Optional(Some(Box::new(
+                execute_or_propagate!(expression, context),
+            )))),
+            Self::Result(Ok(expression)) => Ok(Self::Result(Ok(Box::new(execute_or_propagate!(
+                expression, context
+            ))))),
+            Self::Result(Err(expression)) => Ok(Self::Result(Err(Box::new(
+                execute_or_propagate!(expression, context),
+            )))),
             _ => Ok(self.clone()),
         }
     }
 
+    /// Whether the expression is a signal that interrupts the normal flow of
+    /// execution and has to be propagated up to the loop or function that handles it.
+    pub(crate) fn is_control_flow(&self) -> bool {
+        matches!(self, Self::Break(..) | Self::Continue(_) | Self::Return(_))
+    }
+
     fn as_boolean(&self) -> Option<bool> {
         match self {
             Self::Bool(bool) => Some(*bool),
@@ -68,21 +174,117 @@ impl Expression {
     }
 }
 
-impl From<i32> for Expression {
-    fn from(value: i32) -> Self {
-        Expression::Integer(Integer::I32(value))
+/// Unwraps the value of an `Option` or `Result`, or returns the `None` or `Err` from
+/// the enclosing function the way the `?` operator does.
+fn execute_try(expression: Expression) -> anyhow::Result<Expression> {
+    match expression {
+        Expression::Optional(Some(value)) | Expression::Result(Ok(value)) => Ok(*value),
+        none @ Expression::Optional(None) => Ok(Expression::Return(Box::new(none))),
+        err @ Expression::Result(Err(_)) => Ok(Expression::Return(Box::new(err))),
+        other => Err(anyhow!(
+            "The '?' operator cannot be applied to '{:?}'",
+            other
+        )),
+    }
+}
+
+macro_rules! integer_conversions {
+    ($($type:ty => $variant:ident),*) => {
+        $(
+            impl From<$type> for Expression {
+                fn from(value: $type) -> Self {
+                    Expression::Integer(Integer::$variant(value))
+                }
+            }
+
+            impl TryFrom<Expression> for $type {
+                type Error = anyhow::Error;
+
+                fn try_from(expression: Expression) -> anyhow::Result<Self> {
+                    match expression {
+                        Expression::Integer(Integer::$variant(value)) => Ok(value),
+                        Expression::Integer(integer) => Ok(match integer {
+                            Integer::I8(value) => value.try_into()?,
+                            Integer::I16(value) => value.try_into()?,
+                            Integer::I32(value) => value.try_into()?,
+                            Integer::I64(value) => value.try_into()?,
+                            Integer::Isize(value) => value.try_into()?,
+                            Integer::U8(value) => value.try_into()?,
+                            Integer::U16(value) => value.try_into()?,
+                            Integer::U32(value) => value.try_into()?,
+                            Integer::U64(value) => value.try_into()?,
+                            Integer::Usize(value) => value.try_into()?,
+                        }),
+                        other => Err(anyhow!(
+                            "Cannot convert '{:?}' into {}",
+                            other,
+                            stringify!($type)
+                        )),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_conversions!(
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    isize => Isize,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    usize => Usize
+);
+
+impl From<Integer> for Expression {
+    fn from(value: Integer) -> Self {
+        Expression::Integer(value)
+    }
+}
+
+impl From<f32> for Expression {
+    fn from(value: f32) -> Self {
+        Expression::Float(Float::F32(value))
+    }
+}
+
+impl From<f64> for Expression {
+    fn from(value: f64) -> Self {
+        Expression::Float(Float::F64(value))
+    }
+}
+
+impl From<Float> for Expression {
+    fn from(value: Float) -> Self {
+        Expression::Float(value)
     }
 }
 
-impl From<i64> for Expression {
-    fn from(value: i64) -> Self {
-        Expression::Integer(Integer::I64(value))
+impl From<bool> for Expression {
+    fn from(value: bool) -> Self {
+        Expression::Bool(value)
     }
 }
 
-impl From<usize> for Expression {
-    fn from(value: usize) -> Self {
-        Expression::Integer(Integer::Usize(value))
+impl From<char> for Expression {
+    fn from(value: char) -> Self {
+        Expression::Char(value)
+    }
+}
+
+impl From<String> for Expression {
+    fn from(value: String) -> Self {
+        Expression::String(value)
+    }
+}
+
+impl From<&str> for Expression {
+    fn from(value: &str) -> Self {
+        Expression::String(value.to_owned())
     }
 }
 
@@ -96,3 +298,169 @@ impl<T: Into<Expression>> From<Vec<T>> for Expression {
         Expression::Vector(vector)
     }
 }
+
+impl<T: Into<Expression>> From<Option<T>> for Expression {
+    fn from(value: Option<T>) -> Self {
+        Expression::Optional(value.map(|value| Box::new(value.into())))
+    }
+}
+
+impl<T: Into<Expression>, E: Into<Expression>> From<Result<T, E>> for Expression {
+    fn from(value: Result<T, E>) -> Self {
+        Expression::Result(
+            value
+                .map(|value| Box::new(value.into()))
+                .map_err(|error| Box::new(error.into())),
+        )
+    }
+}
+
+impl<A: Into<Expression>, B: Into<Expression>> From<(A, B)> for Expression {
+    fn from((a, b): (A, B)) -> Self {
+        Expression::Tuple(vec![a.into(), b.into()])
+    }
+}
+
+impl<A: Into<Expression>, B: Into<Expression>, C: Into<Expression>> From<(A, B, C)> for Expression {
+    fn from((a, b, c): (A, B, C)) -> Self {
+        Expression::Tuple(vec![a.into(), b.into(), c.into()])
+    }
+}
+
+impl TryFrom<Expression> for f32 {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Float(Float::F32(value)) => Ok(value),
+            Expression::Float(Float::F64(value)) => Ok(value as f32),
+            other => Err(anyhow!("Cannot convert '{:?}' into f32", other)),
+        }
+    }
+}
+
+impl TryFrom<Expression> for f64 {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Float(float) => Ok(float.as_f64()),
+            other => Err(anyhow!("Cannot convert '{:?}' into f64", other)),
+        }
+    }
+}
+
+impl TryFrom<Expression> for bool {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Bool(value) => Ok(value),
+            other => Err(anyhow!("Cannot convert '{:?}' into bool", other)),
+        }
+    }
+}
+
+impl TryFrom<Expression> for char {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Char(value) => Ok(value),
+            other => Err(anyhow!("Cannot convert '{:?}' into char", other)),
+        }
+    }
+}
+
+impl TryFrom<Expression> for String {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::String(value) => Ok(value),
+            other => Err(anyhow!("Cannot convert '{:?}' into String", other)),
+        }
+    }
+}
+
+impl<T: TryFrom<Expression, Error = anyhow::Error>> TryFrom<Expression> for Vec<T> {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Vector(vector) => vector.into_iter().map(T::try_from).collect(),
+            other => Err(anyhow!("Cannot convert '{:?}' into a vector", other)),
+        }
+    }
+}
+
+impl<T: TryFrom<Expression, Error = anyhow::Error>> TryFrom<Expression> for Option<T> {
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Optional(value) => value.map(|value| T::try_from(*value)).transpose(),
+            other => Err(anyhow!("Cannot convert '{:?}' into an option", other)),
+        }
+    }
+}
+
+impl<T, E> TryFrom<Expression> for Result<T, E>
+where
+    T: TryFrom<Expression, Error = anyhow::Error>,
+    E: TryFrom<Expression, Error = anyhow::Error>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Result(Ok(value)) => Ok(Ok(T::try_from(*value)?)),
+            Expression::Result(Err(error)) => Ok(Err(E::try_from(*error)?)),
+            other => Err(anyhow!("Cannot convert '{:?}' into a result", other)),
+        }
+    }
+}
+
+impl<A, B> TryFrom<Expression> for (A, B)
+where
+    A: TryFrom<Expression, Error = anyhow::Error>,
+    B: TryFrom<Expression, Error = anyhow::Error>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Tuple(tuple) if tuple.len() == 2 => {
+                let mut elements = tuple.into_iter();
+                Ok((
+                    A::try_from(elements.next().unwrap())?,
+                    B::try_from(elements.next().unwrap())?,
+                ))
+            }
+            other => Err(anyhow!("Cannot convert '{:?}' into a pair", other)),
+        }
+    }
+}
+
+impl<A, B, C> TryFrom<Expression> for (A, B, C)
+where
+    A: TryFrom<Expression, Error = anyhow::Error>,
+    B: TryFrom<Expression, Error = anyhow::Error>,
+    C: TryFrom<Expression, Error = anyhow::Error>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(expression: Expression) -> anyhow::Result<Self> {
+        match expression {
+            Expression::Tuple(tuple) if tuple.len() == 3 => {
+                let mut elements = tuple.into_iter();
+                Ok((
+                    A::try_from(elements.next().unwrap())?,
+                    B::try_from(elements.next().unwrap())?,
+                    C::try_from(elements.next().unwrap())?,
+                ))
+            }
+            other => Err(anyhow!("Cannot convert '{:?}' into a triple", other)),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::anyhow;
+
+use crate::{interpreter::context::Context, Expression};
+
+use super::Operation;
+
+/// An assertion like `assert!(i < v.len())` or `assert_eq!(v[i], 0)`, given along with
+/// its source text. A failed assertion stops the execution with an error that has the
+/// text and the values that were compared, like a failed contract condition does.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Assertion {
+    /// `assert!` and `debug_assert!`.
+    Condition(String, Expression),
+    /// `assert_eq!` and `debug_assert_eq!`.
+    Eq(String, Expression, Expression),
+    /// `assert_ne!` and `debug_assert_ne!`.
+    Ne(String, Expression, Expression),
+}
+
+impl Assertion {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        match self {
+            Self::Condition(text, Expression::Operation(operation)) if is_comparison(operation) => {
+                let (lhs, rhs) = operation.operands();
+                let lhs = execute_or_propagate!(lhs, context);
+                let rhs = execute_or_propagate!(rhs, context);
+
+                let result = operation.apply(lhs.clone(), rhs.clone())?;
+                check(text, result, &lhs, &rhs)
+            }
+            Self::Condition(text, condition) => match execute_or_propagate!(condition, context) {
+                Expression::Bool(true) => Ok(Expression::Unit),
+                Expression::Bool(false) => Err(anyhow!("Assertion '{}' failed", text)),
+                other => Err(anyhow!(
+                    "Expected boolean, got '{:?}' when validating '{}'",
+                    other,
+                    text
+                )),
+            },
+            Self::Eq(text, lhs, rhs) | Self::Ne(text, lhs, rhs) => {
+                let lhs = execute_or_propagate!(lhs, context);
+                let rhs = execute_or_propagate!(rhs, context);
+
+                let result = match self {
+                    Self::Eq(..) => lhs == rhs,
+                    _ => lhs != rhs,
+                };
+
+                check(text, Expression::Bool(result), &lhs, &rhs)
+            }
+        }
+    }
+}
+
+fn is_comparison(operation: &Operation) -> bool {
+    matches!(
+        operation,
+        Operation::Eq(..)
+            | Operation::Ne(..)
+            | Operation::Lt(..)
+            | Operation::Lte(..)
+            | Operation::Gt(..)
+            | Operation::Gte(..)
+    )
+}
+
+fn check(
+    text: &str,
+    result: Expression,
+    lhs: &Expression,
+    rhs: &Expression,
+) -> anyhow::Result<Expression> {
+    match result {
+        Expression::Bool(true) => Ok(Expression::Unit),
+        _ => Err(anyhow!(
+            "Assertion '{}' failed: left: '{:?}', right: '{:?}'",
+            text,
+            lhs,
+            rhs
+        )),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{interpreter::context::Context, Expression};
 
-use super::Statement;
+use super::{statements::execute_statements, Statement};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Block {
@@ -9,16 +9,12 @@ pub struct Block {
 
 impl Block {
     pub(crate) fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
-        let mut result = Expression::Unit;
-
         context.push_stack();
 
-        for statement in &self.statements {
-            result = statement.execute(context)?;
-        }
+        let result = execute_statements(&self.statements, context);
 
         context.pop_stack();
 
-        Ok(result)
+        result
     }
 }
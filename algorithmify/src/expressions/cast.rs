@@ -0,0 +1,87 @@
+use anyhow::anyhow;
+
+use crate::{interpreter::context::Context, Expression};
+
+use super::{Float, Integer};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CastType {
+    I8,
+    I16,
+    I32,
+    I64,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+    F32,
+    F64,
+    Char,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cast {
+    pub expression: Expression,
+    pub target: CastType,
+}
+
+/// Casts a primitive number with `as`, following the same truncation, wrapping and
+/// saturation rules as the native code. Only `u8` can be cast into a `char`.
+macro_rules! cast_number {
+    ($value:expr, $target:expr) => {
+        match $target {
+            CastType::I8 => Some(Expression::Integer(Integer::I8($value as i8))),
+            CastType::I16 => Some(Expression::Integer(Integer::I16($value as i16))),
+            CastType::I32 => Some(Expression::Integer(Integer::I32($value as i32))),
+            CastType::I64 => Some(Expression::Integer(Integer::I64($value as i64))),
+            CastType::Isize => Some(Expression::Integer(Integer::Isize($value as isize))),
+            CastType::U8 => Some(Expression::Integer(Integer::U8($value as u8))),
+            CastType::U16 => Some(Expression::Integer(Integer::U16($value as u16))),
+            CastType::U32 => Some(Expression::Integer(Integer::U32($value as u32))),
+            CastType::U64 => Some(Expression::Integer(Integer::U64($value as u64))),
+            CastType::Usize => Some(Expression::Integer(Integer::Usize($value as usize))),
+            CastType::F32 => Some(Expression::Float(Float::F32($value as f32))),
+            CastType::F64 => Some(Expression::Float(Float::F64($value as f64))),
+            CastType::Char => None,
+        }
+    };
+}
+
+impl Cast {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let value = execute_or_propagate!(self.expression, context);
+
+        cast_value(&value, self.target).ok_or(anyhow!(
+            "Cannot cast '{:?}' as {:?}",
+            value,
+            self.target
+        ))
+    }
+}
+
+pub(crate) fn cast_value(value: &Expression, target: CastType) -> Option<Expression> {
+    let is_float_target = matches!(target, CastType::F32 | CastType::F64);
+
+    match value {
+        Expression::Integer(Integer::U8(value)) if target == CastType::Char => {
+            Some(Expression::Char(*value as char))
+        }
+        Expression::Integer(Integer::I8(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::I16(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::I32(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::I64(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::Isize(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::U8(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::U16(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::U32(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::U64(value)) => cast_number!(*value, target),
+        Expression::Integer(Integer::Usize(value)) => cast_number!(*value, target),
+        Expression::Float(Float::F32(value)) => cast_number!(*value, target),
+        Expression::Float(Float::F64(value)) => cast_number!(*value, target),
+        Expression::Char(value) if !is_float_target => cast_number!(*value as u32, target),
+        Expression::Bool(value) if !is_float_target => cast_number!(*value as u8, target),
+        _ => None,
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::anyhow;
+
+use crate::{interpreter::context::Context, Expression};
+
+use super::{
+    functions::{FunctionArgParamPair, FunctionParams},
+    Pattern, Reference,
+};
+
+/// A closure like `|a, b| a < b`. The variables it captures are copied from the
+/// context when the closure is created, so later changes to them aren't seen by it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Closure {
+    pub params: Vec<Pattern>,
+    pub body: Expression,
+    /// The names the body refers to, of which the ones defined where the closure is
+    /// created are captured.
+    pub captures: Vec<String>,
+    pub environment: Vec<FunctionArgParamPair>,
+}
+
+impl Closure {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        if self.captures.is_empty() {
+            return Ok(Expression::Closure(Box::new(self.clone())));
+        }
+
+        // captured views like `s` in `let s = &mut v[a..b]` capture the values they borrow
+        let mut environment = Vec::with_capacity(self.captures.len());
+        for name in &self.captures {
+            let reference = Reference::Variable(name.clone());
+            if context.search_reference(&reference)?.is_some() {
+                environment.push((name.clone(), reference.execute(context)?));
+            }
+        }
+
+        Ok(Expression::Closure(Box::new(Closure {
+            params: self.params.clone(),
+            body: self.body.clone(),
+            captures: Vec::new(),
+            environment,
+        })))
+    }
+
+    pub(crate) fn call(
+        &self,
+        caller: &Context,
+        args: FunctionParams,
+    ) -> anyhow::Result<Expression> {
+        if args.len() != self.params.len() {
+            return Err(anyhow!(
+                "Closure expects {} arguments, got {}",
+                self.params.len(),
+                args.len()
+            ));
+        }
+
+        let mut bindings = Vec::new();
+        for (pattern, arg) in self.params.iter().zip(&args) {
+            if !pattern.matches(arg, &mut bindings) {
+                return Err(anyhow!("Cannot destructure '{:?}' into {:?}", arg, pattern));
+            }
+        }
+
+        let mut context = caller.child(Default::default());
+        context.push_stack_from(self.environment.clone());
+        context.push_stack_from(bindings);
+
+        match self.body.execute(&mut context)? {
+            Expression::Return(result) => Ok(*result),
+            result => Ok(result),
+        }
+    }
+}
+
+/// A call to a closure, like `cmp(a, b)` for a closure bound to `cmp`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosureCall {
+    pub expression: Box<Expression>,
+    pub params: FunctionParams,
+}
+
+impl ClosureCall {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let closure = execute_or_propagate!(self.expression, context);
+
+        let mut args = Vec::with_capacity(self.params.len());
+        for expression in &self.params {
+            args.push(execute_or_propagate!(expression, context));
+        }
+
+        match closure {
+            Expression::Closure(closure) => closure.call(context, args),
+            other => Err(anyhow!("'{:?}' is not a closure", other)),
+        }
+    }
+}
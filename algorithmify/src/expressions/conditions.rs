@@ -1,16 +1,22 @@
+use anyhow::anyhow;
+
 use crate::{interpreter::context::Context, Expression};
 
-use super::Statement;
+use super::{statements::execute_statements, Pattern, Statement};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Condition {
     If(If),
+    IfLet(IfLet),
+    Match(Match),
 }
 
 impl Condition {
     pub fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
         match self {
             Self::If(condition) => condition.execute(context),
+            Self::IfLet(condition) => condition.execute(context),
+            Self::Match(condition) => condition.execute(context),
         }
     }
 }
@@ -29,9 +35,7 @@ impl If {
         context.push_stack();
 
         if let Expression::Bool(true) = self.condition.execute(context)? {
-            for statement in &self.statements {
-                result = statement.execute(context)?;
-            }
+            result = execute_statements(&self.statements, context)?;
         } else {
             if let Some(else_clause) = &self.else_clause {
                 result = else_clause.execute(context)?;
@@ -43,3 +47,82 @@ impl If {
         Ok(result)
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfLet {
+    pub statements: Vec<Statement>,
+    pub else_clause: Option<Expression>,
+    pub pattern: Pattern,
+    pub expression: Expression,
+}
+
+impl IfLet {
+    fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
+        let value = execute_or_propagate!(self.expression, context);
+
+        let mut bindings = Vec::new();
+        if self.pattern.matches(&value, &mut bindings) {
+            context.push_stack_from(bindings);
+            let result = execute_statements(&self.statements, context);
+            context.pop_stack();
+            result
+        } else if let Some(else_clause) = &self.else_clause {
+            else_clause.execute(context)
+        } else {
+            Ok(Expression::Unit)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub expression: Expression,
+    pub arms: Vec<MatchArm>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Expression>,
+    pub expression: Expression,
+}
+
+impl Match {
+    fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
+        let value = execute_or_propagate!(self.expression, context);
+
+        for arm in &self.arms {
+            let mut bindings = Vec::new();
+            if !arm.pattern.matches(&value, &mut bindings) {
+                continue;
+            }
+
+            context.push_stack_from(bindings);
+            let result = arm.execute(context);
+            context.pop_stack();
+
+            if let Some(result) = result? {
+                return Ok(result);
+            }
+        }
+
+        Err(anyhow!("No match arm matches the value '{:?}'", value))
+    }
+}
+
+impl MatchArm {
+    /// The result of the arm if its guard holds, with the bindings of its pattern in scope.
+    fn execute(&self, context: &mut Context) -> anyhow::Result<Option<Expression>> {
+        let guard = match &self.guard {
+            Some(guard) => guard.execute(context)?,
+            None => Expression::Bool(true),
+        };
+
+        match guard {
+            Expression::Bool(true) => Ok(Some(self.expression.execute(context)?)),
+            Expression::Bool(false) => Ok(None),
+            signal if signal.is_control_flow() => Ok(Some(signal)),
+            other => Err(anyhow!("Expected boolean match guard, got '{:?}'", other)),
+        }
+    }
+}
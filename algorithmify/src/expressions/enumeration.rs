@@ -0,0 +1,23 @@
+use crate::{interpreter::context::Context, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+    pub name: String,
+    pub variant: String,
+    pub payload: Vec<Expression>,
+}
+
+impl Enum {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let mut payload = Vec::with_capacity(self.payload.len());
+        for expression in &self.payload {
+            payload.push(execute_or_propagate!(expression, context));
+        }
+
+        Ok(Expression::Enum(Enum {
+            name: self.name.clone(),
+            variant: self.variant.clone(),
+            payload,
+        }))
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub enum Float {
@@ -7,12 +7,35 @@ pub enum Float {
 }
 
 impl Float {
-    fn as_f64(&self) -> f64 {
+    pub fn as_f64(&self) -> f64 {
         match self {
             Self::F32(value) => *value as f64,
             Self::F64(value) => *value,
         }
     }
+
+    /// Unsuffixed float literals are interpreted as `f64`, so mixing them with an `f32`
+    /// computes in `f32`, which is what the native code infers for the literal.
+    fn as_f32(&self) -> f32 {
+        match self {
+            Self::F32(value) => *value,
+            Self::F64(value) => *value as f32,
+        }
+    }
+
+    pub fn abs(self) -> Self {
+        match self {
+            Self::F32(value) => Self::F32(value.abs()),
+            Self::F64(value) => Self::F64(value.abs()),
+        }
+    }
+
+    pub fn sqrt(self) -> Self {
+        match self {
+            Self::F32(value) => Self::F32(value.sqrt()),
+            Self::F64(value) => Self::F64(value.sqrt()),
+        }
+    }
 }
 
 impl Add for Float {
@@ -22,7 +45,7 @@ impl Add for Float {
         match (self, rhs) {
             (Self::F32(lhs), Self::F32(rhs)) => Self::F32(lhs + rhs),
             (Self::F64(lhs), Self::F64(rhs)) => Self::F64(lhs + rhs),
-            _ => Self::F64(self.as_f64() + rhs.as_f64()),
+            _ => Self::F32(self.as_f32() + rhs.as_f32()),
         }
     }
 }
@@ -34,7 +57,7 @@ impl Sub for Float {
         match (self, rhs) {
             (Self::F32(lhs), Self::F32(rhs)) => Self::F32(lhs - rhs),
             (Self::F64(lhs), Self::F64(rhs)) => Self::F64(lhs - rhs),
-            _ => Self::F64(self.as_f64() - rhs.as_f64()),
+            _ => Self::F32(self.as_f32() - rhs.as_f32()),
         }
     }
 }
@@ -46,7 +69,7 @@ impl Mul for Float {
         match (self, rhs) {
             (Self::F32(lhs), Self::F32(rhs)) => Self::F32(lhs * rhs),
             (Self::F64(lhs), Self::F64(rhs)) => Self::F64(lhs * rhs),
-            _ => Self::F64(self.as_f64() * rhs.as_f64()),
+            _ => Self::F32(self.as_f32() * rhs.as_f32()),
         }
     }
 }
@@ -58,7 +81,30 @@ impl Div for Float {
         match (self, rhs) {
             (Self::F32(lhs), Self::F32(rhs)) => Self::F32(lhs / rhs),
             (Self::F64(lhs), Self::F64(rhs)) => Self::F64(lhs / rhs),
-            _ => Self::F64(self.as_f64() / rhs.as_f64()),
+            _ => Self::F32(self.as_f32() / rhs.as_f32()),
+        }
+    }
+}
+
+impl Rem for Float {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::F32(lhs), Self::F32(rhs)) => Self::F32(lhs % rhs),
+            (Self::F64(lhs), Self::F64(rhs)) => Self::F64(lhs % rhs),
+            _ => Self::F32(self.as_f32() % rhs.as_f32()),
+        }
+    }
+}
+
+impl Neg for Float {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::F32(value) => Self::F32(-value),
+            Self::F64(value) => Self::F64(-value),
         }
     }
 }
@@ -0,0 +1,141 @@
+use anyhow::anyhow;
+
+use crate::{interpreter::context::Context, Expression};
+
+use super::Float;
+
+/// A `format!` string, split into the text around its placeholders and the arguments
+/// that go in each of them, so `pieces` has one more element than `args`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Format {
+    pub pieces: Vec<String>,
+    pub args: Vec<FormatArg>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatArg {
+    /// An argument for a `{}` placeholder.
+    Display(Expression),
+    /// An argument for a `{:?}` placeholder.
+    Debug(Expression),
+}
+
+impl Format {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let mut text = self.pieces[0].clone();
+
+        for (arg, piece) in self.args.iter().zip(&self.pieces[1..]) {
+            match arg {
+                FormatArg::Display(expression) => {
+                    text += &display(&execute_or_propagate!(expression, context))?
+                }
+                FormatArg::Debug(expression) => {
+                    text += &debug(&execute_or_propagate!(expression, context))?
+                }
+            }
+
+            text += piece;
+        }
+
+        Ok(Expression::String(text))
+    }
+}
+
+/// A `print!` or `println!`, whose text is written to the output of the interpreter.
+/// `eprint!` and `eprintln!` write to the same output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Print {
+    pub format: Format,
+    pub newline: bool,
+}
+
+impl Print {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let mut text = match self.format.execute(context)? {
+            Expression::String(text) => text,
+            signal => return Ok(signal),
+        };
+
+        if self.newline {
+            text.push('\n');
+        }
+
+        context.write_output(&text);
+
+        Ok(Expression::Unit)
+    }
+}
+
+/// Formats a value like its native counterpart does with `{}`.
+fn display(expression: &Expression) -> anyhow::Result<String> {
+    match expression {
+        Expression::Integer(integer) => Ok(integer.to_string()),
+        Expression::Float(Float::F32(value)) => Ok(value.to_string()),
+        Expression::Float(Float::F64(value)) => Ok(value.to_string()),
+        Expression::Char(character) => Ok(character.to_string()),
+        Expression::String(string) => Ok(string.clone()),
+        Expression::Bool(value) => Ok(value.to_string()),
+        other => Err(anyhow!("'{:?}' cannot be formatted with '{{}}'", other)),
+    }
+}
+
+/// Formats a value like its native counterpart does with `{:?}`.
+fn debug(expression: &Expression) -> anyhow::Result<String> {
+    let list = |elements: &[Expression]| {
+        elements
+            .iter()
+            .map(debug)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(|elements| elements.join(", "))
+    };
+
+    match expression {
+        Expression::Unit => Ok("()".to_owned()),
+        Expression::Integer(integer) => Ok(integer.to_string()),
+        Expression::Float(Float::F32(value)) => Ok(format!("{:?}", value)),
+        Expression::Float(Float::F64(value)) => Ok(format!("{:?}", value)),
+        Expression::Char(character) => Ok(format!("{:?}", character)),
+        Expression::String(string) => Ok(format!("{:?}", string)),
+        Expression::Bool(value) => Ok(value.to_string()),
+        Expression::Vector(elements) => Ok(format!("[{}]", list(elements)?)),
+        Expression::Tuple(elements) if elements.len() == 1 => {
+            Ok(format!("({},)", debug(&elements[0])?))
+        }
+        Expression::Tuple(elements) => Ok(format!("({})", list(elements)?)),
+        Expression::Optional(None) => Ok("None".to_owned()),
+        Expression::Optional(Some(value)) => Ok(format!("Some({})", debug(value)?)),
+        Expression::Result(Ok(value)) => Ok(format!("Ok({})", debug(value)?)),
+        Expression::Result(Err(error)) => Ok(format!("Err({})", debug(error)?)),
+        Expression::Struct(structure) => {
+            let fields = structure
+                .fields
+                .iter()
+                .map(|(name, value)| Ok(format!("{}: {}", name, debug(value)?)))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            Ok(format!("{} {{ {} }}", structure.name, fields.join(", ")))
+        }
+        Expression::Enum(enumeration) if enumeration.payload.is_empty() => {
+            Ok(enumeration.variant.clone())
+        }
+        Expression::Enum(enumeration) => Ok(format!(
+            "{}({})",
+            enumeration.variant,
+            list(&enumeration.payload)?
+        )),
+        Expression::Range(range) => {
+            let bound = |bound: &Option<Expression>| match bound {
+                Some(bound) => debug(bound),
+                None => Ok(String::new()),
+            };
+
+            Ok(format!(
+                "{}..{}{}",
+                bound(&range.start)?,
+                if range.inclusive { "=" } else { "" },
+                bound(&range.end)?
+            ))
+        }
+        other => Err(anyhow!("'{:?}' cannot be formatted with '{{:?}}'", other)),
+    }
+}
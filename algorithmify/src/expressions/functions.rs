@@ -1,49 +1,138 @@
-use super::{statements::Statement, Expression, Reference};
+use super::{
+    statements::{execute_statements, Statement},
+    Expression, Reference, Type,
+};
 use crate::{interpreter::context::Context, interpreter::context::ContractMap};
 use anyhow::anyhow;
 
 pub type FunctionBuilder = fn() -> Function;
-pub type FunctionArgs = Vec<String>;
+pub type FunctionArgs = Vec<(String, Type)>;
 pub type FunctionParams = Vec<Expression>;
 pub type FunctionArgParamPair = (String, Expression);
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Function {
+    pub(crate) name: String,
+    pub(crate) generics: Vec<String>,
     pub(crate) args: FunctionArgs,
+    pub(crate) return_type: Type,
     pub(crate) statements: Vec<Statement>,
     pub(crate) contracts: ContractMap,
 }
 
 impl Function {
-    pub fn new(args: FunctionArgs, statements: Vec<Statement>, contracts: ContractMap) -> Self {
+    pub fn new(
+        name: String,
+        generics: Vec<String>,
+        args: FunctionArgs,
+        return_type: Type,
+        statements: Vec<Statement>,
+        contracts: ContractMap,
+    ) -> Self {
         Self {
+            name,
+            generics,
             args,
+            return_type,
             statements,
             contracts,
         }
     }
 
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn generics(&self) -> &[String] {
+        &self.generics
+    }
+
+    pub fn args(&self) -> &[(String, Type)] {
+        &self.args
+    }
+
+    pub fn return_type(&self) -> &Type {
+        &self.return_type
+    }
+
+    /// Whether the argument at the given position is taken by `&mut` reference.
+    pub(crate) fn is_mutable_arg(&self, index: usize) -> bool {
+        matches!(self.args.get(index), Some((_, Type::MutableReference(_))))
+    }
+
+    /// Checks that the arguments match the parameters of the function in number and
+    /// kind, so that a wrong call fails instead of binding the wrong values.
+    pub(crate) fn validate_args(&self, args: &[Expression]) -> anyhow::Result<()> {
+        if args.len() != self.args.len() {
+            return Err(anyhow!(
+                "Function '{}' expects {} arguments, got {}",
+                self.name,
+                self.args.len(),
+                args.len()
+            ));
+        }
+
+        for ((name, arg_type), arg) in self.args.iter().zip(args) {
+            if !arg_type.accepts(arg, &self.generics) {
+                return Err(anyhow!(
+                    "Argument '{}' of function '{}' expects {:?}, got '{:?}'",
+                    name,
+                    self.name,
+                    arg_type,
+                    arg
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn execute(
         &self,
         context: &mut Context,
         args: FunctionParams,
     ) -> anyhow::Result<Expression> {
-        let arg_pairs = self.args.iter().cloned().zip(args.into_iter()).collect();
+        Ok(self.execute_with_mutable_args(context, args)?.0)
+    }
+
+    /// Executes the function, returning along with its result the final values of the
+    /// arguments it takes by `&mut` reference, given by their position.
+    pub(crate) fn execute_with_mutable_args(
+        &self,
+        context: &mut Context,
+        args: FunctionParams,
+    ) -> anyhow::Result<(Expression, Vec<(usize, Expression)>)> {
+        self.validate_args(&args)?;
+
+        let arg_pairs = self
+            .args
+            .iter()
+            .zip(args)
+            .map(|((name, arg_type), arg)| Ok((name.clone(), arg_type.conform(arg)?)))
+            .collect::<anyhow::Result<_>>()?;
         context.push_stack_from(arg_pairs);
 
-        for statement in &self.statements[0..self.statements.len() - 1] {
-            statement.execute(context)?;
-        }
+        let result = match execute_statements(&self.statements, context)? {
+            Expression::Return(result) => *result,
+            result => result,
+        };
 
-        let result = if let Some(last_statement) = self.statements.last() {
-            last_statement.execute(context)
-        } else {
-            Ok(Expression::Unit)
-        }?;
+        let mutable_args = self
+            .args
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, arg_type))| matches!(arg_type, Type::MutableReference(_)))
+            .map(|(index, (name, _))| {
+                Ok(context
+                    .search_reference(&Reference::Variable(name.clone()))?
+                    .map(|value| (index, value.clone())))
+            })
+            .filter_map(Result::transpose)
+            .collect::<anyhow::Result<_>>()?;
 
         context.pop_stack();
 
-        Ok(result)
+        Ok((self.return_type.conform(result)?, mutable_args))
     }
 
     pub(crate) fn extract_args_from_context(
@@ -52,9 +141,9 @@ impl Function {
     ) -> anyhow::Result<Vec<Expression>> {
         self.args
             .iter()
-            .map(|arg| {
+            .map(|(arg, _)| {
                 context
-                    .search_reference(&Reference::Variable(arg.clone()))
+                    .search_reference(&Reference::Variable(arg.clone()))?
                     .cloned()
                     .ok_or(anyhow!(
                         "Cannot extract field '{}' from context. Field not found.",
@@ -65,6 +154,7 @@ impl Function {
     }
 }
 
+#[allow(unpredictable_function_pointer_comparisons)]
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionCall {
     pub builder: FunctionBuilder,
@@ -73,14 +163,60 @@ pub struct FunctionCall {
 
 impl FunctionCall {
     pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
-        let args = self
-            .params
-            .iter()
-            .map(|expression| expression.execute(context))
-            .collect::<anyhow::Result<Vec<_>>>()?;
-
         let function = (self.builder)();
-        let mut child_context = Context::new(function.contracts.clone());
-        function.execute(&mut child_context, args)
+
+        let mut args = Vec::with_capacity(self.params.len());
+        let mut places = Vec::with_capacity(self.params.len());
+        for (index, expression) in self.params.iter().enumerate() {
+            let (arg, place) = evaluate_arg(context, expression, function.is_mutable_arg(index))?;
+            if arg.is_control_flow() {
+                return Ok(arg);
+            }
+
+            args.push(arg);
+            places.push(place);
+        }
+
+        let mut child_context = context.child(function.contracts.clone());
+        let (result, mutable_args) =
+            function.execute_with_mutable_args(&mut child_context, args)?;
+
+        write_back_mutable_args(context, &places, mutable_args)?;
+
+        Ok(result)
+    }
+}
+
+/// Evaluates an argument of a call. The place an argument taken by `&mut` reference is
+/// borrowed from, like `v[g()]` in `f(&mut v[g()])`, is resolved once before the call
+/// and returned along with its value, so that its final value is written back to it.
+pub(crate) fn evaluate_arg(
+    context: &mut Context,
+    expression: &Expression,
+    is_mutable: bool,
+) -> anyhow::Result<(Expression, Option<Reference>)> {
+    if is_mutable {
+        if let Some(reference) = expression.to_reference(context)? {
+            return Ok((reference.execute(context)?, Some(reference)));
+        }
     }
+
+    Ok((expression.execute(context)?, None))
+}
+
+/// Writes the final values of the arguments passed by `&mut` reference to the places
+/// they were borrowed from, like `v` in `swap(&mut v, i, j)`. Arguments that aren't
+/// places, like `&mut vec![]`, are temporaries whose final value is dropped.
+pub(crate) fn write_back_mutable_args(
+    context: &mut Context,
+    places: &[Option<Reference>],
+    mutable_args: Vec<(usize, Expression)>,
+) -> anyhow::Result<()> {
+    for (index, value) in mutable_args {
+        if let Some(reference) = &places[index] {
+            context.insert_or_update_in_heap(reference, value)?;
+        }
+    }
+
+    Ok(())
 }
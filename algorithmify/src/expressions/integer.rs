@@ -1,4 +1,6 @@
-use std::ops::{Add, BitAnd, BitOr, Div, Mul, Sub};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Sub};
+
+use anyhow::anyhow;
 
 #[derive(Debug, Copy, Clone)]
 pub enum Integer {
@@ -30,6 +32,23 @@ impl Integer {
         }
     }
 
+    /// The value in a type wide enough for values of any of the integer types, to
+    /// compare integers of different types.
+    fn as_i128(&self) -> i128 {
+        match self {
+            Self::I8(value) => *value as i128,
+            Self::I16(value) => *value as i128,
+            Self::I32(value) => *value as i128,
+            Self::I64(value) => *value as i128,
+            Self::Isize(value) => *value as i128,
+            Self::U8(value) => *value as i128,
+            Self::U16(value) => *value as i128,
+            Self::U32(value) => *value as i128,
+            Self::U64(value) => *value as i128,
+            Self::Usize(value) => *value as i128,
+        }
+    }
+
     pub fn as_usize(&self) -> usize {
         match self {
             Self::I8(value) => *value as usize,
@@ -44,6 +63,158 @@ impl Integer {
             Self::Usize(value) => *value,
         }
     }
+
+    /// Unsuffixed literals are interpreted as `i32`, so an `i32` mixed with an integer
+    /// of another type takes that type, which is what the native code infers for the
+    /// literal. Its bits are kept, so that `mask & !3` clears the same bits of a `u8`
+    /// as natively. Other mixes fall back to `i64`.
+    fn unify(self, rhs: Self) -> Option<(Self, Self)> {
+        match (self, rhs) {
+            (Self::I32(_), Self::I32(_)) => None,
+            (Self::I32(lhs), rhs) => Some((Self::I32(lhs).with_type_of(rhs), rhs)),
+            (lhs, Self::I32(rhs)) => Some((lhs, Self::I32(rhs).with_type_of(lhs))),
+            _ => None,
+        }
+    }
+
+    /// Gives an `i32` operand of an arithmetic operation or comparison the type of the
+    /// other operand, like `unify` does, failing when its value doesn't fit in that type
+    /// instead of wrapping it.
+    pub(crate) fn unify_operands(self, rhs: Self) -> anyhow::Result<(Self, Self)> {
+        let retype = |value: Self, other: Self| {
+            value.try_with_type_of(other).ok_or(anyhow!(
+                "Cannot use '{:?}' as a value of the type of '{:?}'",
+                value,
+                other
+            ))
+        };
+
+        match (self, rhs) {
+            (Self::I32(_), Self::I32(_)) => Ok((self, rhs)),
+            (lhs @ Self::I32(_), rhs) => Ok((retype(lhs, rhs)?, rhs)),
+            (lhs, rhs @ Self::I32(_)) => Ok((lhs, retype(rhs, lhs)?)),
+            _ => Ok((self, rhs)),
+        }
+    }
+
+    pub(crate) fn with_type_of(self, other: Self) -> Self {
+        let value = self.as_i64();
+        match other {
+            Self::I8(_) => Self::I8(value as i8),
+            Self::I16(_) => Self::I16(value as i16),
+            Self::I32(_) => Self::I32(value as i32),
+            Self::I64(_) => Self::I64(value),
+            Self::Isize(_) => Self::Isize(value as isize),
+            Self::U8(_) => Self::U8(value as u8),
+            Self::U16(_) => Self::U16(value as u16),
+            Self::U32(_) => Self::U32(value as u32),
+            Self::U64(_) => Self::U64(value as u64),
+            Self::Usize(_) => Self::Usize(value as usize),
+        }
+    }
+
+    /// The value with the type of the other integer, or `None` if it doesn't fit in it.
+    pub(crate) fn try_with_type_of(self, other: Self) -> Option<Self> {
+        let value = self.as_i128();
+        Some(match other {
+            Self::I8(_) => Self::I8(value.try_into().ok()?),
+            Self::I16(_) => Self::I16(value.try_into().ok()?),
+            Self::I32(_) => Self::I32(value.try_into().ok()?),
+            Self::I64(_) => Self::I64(value.try_into().ok()?),
+            Self::Isize(_) => Self::Isize(value.try_into().ok()?),
+            Self::U8(_) => Self::U8(value.try_into().ok()?),
+            Self::U16(_) => Self::U16(value.try_into().ok()?),
+            Self::U32(_) => Self::U32(value.try_into().ok()?),
+            Self::U64(_) => Self::U64(value.try_into().ok()?),
+            Self::Usize(_) => Self::Usize(value.try_into().ok()?),
+        })
+    }
+}
+
+/// Applies a checked arithmetic method to two integers of the same type, unifying
+/// mixed operands like the arithmetic operators do.
+macro_rules! checked_operation {
+    ($lhs:expr, $rhs:expr, $method:ident) => {
+        match ($lhs, $rhs) {
+            (Integer::I8(lhs), Integer::I8(rhs)) => lhs.$method(rhs).map(Integer::I8),
+            (Integer::I16(lhs), Integer::I16(rhs)) => lhs.$method(rhs).map(Integer::I16),
+            (Integer::I32(lhs), Integer::I32(rhs)) => lhs.$method(rhs).map(Integer::I32),
+            (Integer::I64(lhs), Integer::I64(rhs)) => lhs.$method(rhs).map(Integer::I64),
+            (Integer::Isize(lhs), Integer::Isize(rhs)) => lhs.$method(rhs).map(Integer::Isize),
+            (Integer::U8(lhs), Integer::U8(rhs)) => lhs.$method(rhs).map(Integer::U8),
+            (Integer::U16(lhs), Integer::U16(rhs)) => lhs.$method(rhs).map(Integer::U16),
+            (Integer::U32(lhs), Integer::U32(rhs)) => lhs.$method(rhs).map(Integer::U32),
+            (Integer::U64(lhs), Integer::U64(rhs)) => lhs.$method(rhs).map(Integer::U64),
+            (Integer::Usize(lhs), Integer::Usize(rhs)) => lhs.$method(rhs).map(Integer::Usize),
+            (lhs, rhs) => match lhs.unify(rhs) {
+                Some((lhs, rhs)) => lhs.$method(rhs),
+                None => lhs.as_i64().$method(rhs.as_i64()).map(Integer::I64),
+            },
+        }
+    };
+}
+
+/// Applies a checked shift method to an integer, by an amount of any integer type.
+macro_rules! checked_shift {
+    ($lhs:expr, $rhs:expr, $method:ident) => {{
+        let rhs = u32::try_from($rhs.as_i64()).ok()?;
+        match $lhs {
+            Integer::I8(lhs) => lhs.$method(rhs).map(Integer::I8),
+            Integer::I16(lhs) => lhs.$method(rhs).map(Integer::I16),
+            Integer::I32(lhs) => lhs.$method(rhs).map(Integer::I32),
+            Integer::I64(lhs) => lhs.$method(rhs).map(Integer::I64),
+            Integer::Isize(lhs) => lhs.$method(rhs).map(Integer::Isize),
+            Integer::U8(lhs) => lhs.$method(rhs).map(Integer::U8),
+            Integer::U16(lhs) => lhs.$method(rhs).map(Integer::U16),
+            Integer::U32(lhs) => lhs.$method(rhs).map(Integer::U32),
+            Integer::U64(lhs) => lhs.$method(rhs).map(Integer::U64),
+            Integer::Usize(lhs) => lhs.$method(rhs).map(Integer::Usize),
+        }
+    }};
+}
+
+impl Integer {
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        checked_operation!(self, rhs, checked_add)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        checked_operation!(self, rhs, checked_sub)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        checked_operation!(self, rhs, checked_mul)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        checked_operation!(self, rhs, checked_div)
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        checked_operation!(self, rhs, checked_rem)
+    }
+
+    /// Shifts keep the type of the left operand, whatever the type of the amount is,
+    /// and return `None` for negative amounts or amounts past the width of the type.
+    pub fn checked_shl(self, rhs: Self) -> Option<Self> {
+        checked_shift!(self, rhs, checked_shl)
+    }
+
+    pub fn checked_shr(self, rhs: Self) -> Option<Self> {
+        checked_shift!(self, rhs, checked_shr)
+    }
+
+    /// Negates a signed integer, returning `None` for unsigned ones.
+    pub fn negate(self) -> Option<Self> {
+        match self {
+            Self::I8(value) => Some(Self::I8(-value)),
+            Self::I16(value) => Some(Self::I16(-value)),
+            Self::I32(value) => Some(Self::I32(-value)),
+            Self::I64(value) => Some(Self::I64(-value)),
+            Self::Isize(value) => Some(Self::Isize(-value)),
+            _ => None,
+        }
+    }
 }
 
 impl Add for Integer {
@@ -61,7 +232,10 @@ impl Add for Integer {
             (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs + rhs),
             (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs + rhs),
             (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs + rhs),
-            _ => Self::I64(self.as_i64() + rhs.as_i64()),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs + rhs,
+                None => Self::I64(self.as_i64() + rhs.as_i64()),
+            },
         }
     }
 }
@@ -81,7 +255,10 @@ impl Sub for Integer {
             (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs - rhs),
             (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs - rhs),
             (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs - rhs),
-            _ => Self::I64(self.as_i64() - rhs.as_i64()),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs - rhs,
+                None => Self::I64(self.as_i64() - rhs.as_i64()),
+            },
         }
     }
 }
@@ -101,7 +278,10 @@ impl Mul for Integer {
             (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs * rhs),
             (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs * rhs),
             (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs * rhs),
-            _ => Self::I64(self.as_i64() * rhs.as_i64()),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs * rhs,
+                None => Self::I64(self.as_i64() * rhs.as_i64()),
+            },
         }
     }
 }
@@ -121,7 +301,10 @@ impl Div for Integer {
             (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs / rhs),
             (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs / rhs),
             (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs / rhs),
-            _ => Self::I64(self.as_i64() / rhs.as_i64()),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs / rhs,
+                None => Self::I64(self.as_i64() / rhs.as_i64()),
+            },
         }
     }
 }
@@ -141,7 +324,10 @@ impl BitAnd for Integer {
             (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs & rhs),
             (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs & rhs),
             (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs & rhs),
-            _ => Self::I64(self.as_i64() & rhs.as_i64()),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs & rhs,
+                None => Self::I64(self.as_i64() & rhs.as_i64()),
+            },
         }
     }
 }
@@ -161,7 +347,52 @@ impl BitOr for Integer {
             (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs | rhs),
             (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs | rhs),
             (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs | rhs),
-            _ => Self::I64(self.as_i64() | rhs.as_i64()),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs | rhs,
+                None => Self::I64(self.as_i64() | rhs.as_i64()),
+            },
+        }
+    }
+}
+
+impl BitXor for Integer {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::I8(lhs), Self::I8(rhs)) => Self::I8(lhs ^ rhs),
+            (Self::I16(lhs), Self::I16(rhs)) => Self::I16(lhs ^ rhs),
+            (Self::I32(lhs), Self::I32(rhs)) => Self::I32(lhs ^ rhs),
+            (Self::I64(lhs), Self::I64(rhs)) => Self::I64(lhs ^ rhs),
+            (Self::Isize(lhs), Self::Isize(rhs)) => Self::Isize(lhs ^ rhs),
+            (Self::U8(lhs), Self::U8(rhs)) => Self::U8(lhs ^ rhs),
+            (Self::U16(lhs), Self::U16(rhs)) => Self::U16(lhs ^ rhs),
+            (Self::U32(lhs), Self::U32(rhs)) => Self::U32(lhs ^ rhs),
+            (Self::U64(lhs), Self::U64(rhs)) => Self::U64(lhs ^ rhs),
+            (Self::Usize(lhs), Self::Usize(rhs)) => Self::Usize(lhs ^ rhs),
+            _ => match self.unify(rhs) {
+                Some((lhs, rhs)) => lhs ^ rhs,
+                None => Self::I64(self.as_i64() ^ rhs.as_i64()),
+            },
+        }
+    }
+}
+
+impl Not for Integer {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::I8(value) => Self::I8(!value),
+            Self::I16(value) => Self::I16(!value),
+            Self::I32(value) => Self::I32(!value),
+            Self::I64(value) => Self::I64(!value),
+            Self::Isize(value) => Self::Isize(!value),
+            Self::U8(value) => Self::U8(!value),
+            Self::U16(value) => Self::U16(!value),
+            Self::U32(value) => Self::U32(!value),
+            Self::U64(value) => Self::U64(!value),
+            Self::Usize(value) => Self::Usize(!value),
         }
     }
 }
@@ -179,13 +410,30 @@ impl PartialEq for Integer {
             (Self::U32(l0), Self::U32(r0)) => l0 == r0,
             (Self::U64(l0), Self::U64(r0)) => l0 == r0,
             (Self::Usize(l0), Self::Usize(r0)) => l0 == r0,
-            _ => self.as_i64() == other.as_i64(),
+            _ => self.as_i128() == other.as_i128(),
         }
     }
 }
 
 impl PartialOrd for Integer {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.as_i64().partial_cmp(&other.as_i64())
+        self.as_i128().partial_cmp(&other.as_i128())
+    }
+}
+
+impl std::fmt::Display for Integer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::I8(value) => write!(f, "{}", value),
+            Self::I16(value) => write!(f, "{}", value),
+            Self::I32(value) => write!(f, "{}", value),
+            Self::I64(value) => write!(f, "{}", value),
+            Self::Isize(value) => write!(f, "{}", value),
+            Self::U8(value) => write!(f, "{}", value),
+            Self::U16(value) => write!(f, "{}", value),
+            Self::U32(value) => write!(f, "{}", value),
+            Self::U64(value) => write!(f, "{}", value),
+            Self::Usize(value) => write!(f, "{}", value),
+        }
     }
 }
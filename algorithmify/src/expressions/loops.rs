@@ -2,12 +2,18 @@ use anyhow::anyhow;
 
 use crate::{interpreter::context::Context, Expression};
 
-use super::{FunctionBuilder, Reference, Statement};
+use super::{
+    functions::FunctionArgParamPair, statements::execute_statements, FunctionBuilder, Integer,
+    Pattern, Range, Reference, Statement,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Loop {
     While(WhileLoop),
+    WhileLet(WhileLetLoop),
     RangedFor(RangedForLoop),
+    IteratorFor(IteratorForLoop),
+    Infinite(InfiniteLoop),
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -22,7 +28,7 @@ impl Contract {
         if let Some((name, pre_condition)) = self.pre_condition.as_ref() {
             let function = pre_condition();
             let args = function.extract_args_from_context(context)?;
-            return match pre_condition().execute(context, args)? {
+            match pre_condition().execute(context, args)? {
                 Expression::Bool(true) => Ok(()),
                 Expression::Bool(false) => Err(anyhow!("Pre-condition '{}' failed", name)),
                 other => Err(anyhow!(
@@ -30,7 +36,7 @@ impl Contract {
                     other,
                     name
                 )),
-            };
+            }
         } else {
             Ok(())
         }
@@ -43,7 +49,7 @@ impl Contract {
         if let Some((name, maintenance_condition)) = self.maintenance_condition.as_ref() {
             let function = maintenance_condition();
             let args = function.extract_args_from_context(context)?;
-            return match function.execute(context, args)? {
+            match function.execute(context, args)? {
                 Expression::Bool(true) => Ok(()),
                 Expression::Bool(false) => Err(anyhow!("Maintenance condition '{}' failed", name)),
                 other => Err(anyhow!(
@@ -51,7 +57,7 @@ impl Contract {
                     other,
                     name
                 )),
-            };
+            }
         } else {
             Ok(())
         }
@@ -61,7 +67,7 @@ impl Contract {
         if let Some((name, post_condition)) = self.post_condition.as_ref() {
             let function = post_condition();
             let args = function.extract_args_from_context(context)?;
-            return match function.execute(context, args)? {
+            match function.execute(context, args)? {
                 Expression::Bool(true) => Ok(()),
                 Expression::Bool(false) => Err(anyhow!("Post-condition '{}' failed", name)),
                 other => Err(anyhow!(
@@ -69,7 +75,7 @@ impl Contract {
                     other,
                     name
                 )),
-            };
+            }
         } else {
             Ok(())
         }
@@ -80,7 +86,10 @@ impl Loop {
     pub fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
         match self {
             Self::While(while_loop) => while_loop.execute(context),
+            Self::WhileLet(while_loop) => while_loop.execute(context),
             Self::RangedFor(for_loop) => for_loop.execute(context),
+            Self::IteratorFor(for_loop) => for_loop.execute(context),
+            Self::Infinite(infinite_loop) => infinite_loop.execute(context),
         }
     }
 }
@@ -100,22 +109,66 @@ impl WhileLoop {
 
         contract.validate_pre_condition(context)?;
 
-        'main: while let Expression::Bool(true) = self.condition.execute(context)? {
+        while let Expression::Bool(true) = self.condition.execute(context)? {
             context.push_stack();
+            let cycle_result = execute_statements(&self.statements, context)?;
+            context.pop_stack();
 
-            for statement in &self.statements {
-                result = statement.execute(context)?;
-                if result == Expression::Break {
-                    break 'main;
-                }
+            if let Some(loop_result) = loop_result(self.tag.as_ref(), cycle_result) {
+                result = loop_result;
+                break;
             }
 
+            contract.validate_maintenance_condition(context)?;
+        }
+
+        if !matches!(result, Expression::Return(_)) {
+            contract.validate_post_condition(context)?;
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhileLetLoop {
+    pub tag: Option<String>,
+    pub statements: Vec<Statement>,
+    pub pattern: Pattern,
+    pub expression: Expression,
+}
+
+impl WhileLetLoop {
+    fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
+        let mut result = Expression::Unit;
+
+        let contract = context.get_contract(self.tag.as_ref());
+
+        contract.validate_pre_condition(context)?;
+
+        loop {
+            let value = execute_or_propagate!(self.expression, context);
+
+            let mut bindings = Vec::new();
+            if !self.pattern.matches(&value, &mut bindings) {
+                break;
+            }
+
+            context.push_stack_from(bindings);
+            let cycle_result = execute_statements(&self.statements, context)?;
             context.pop_stack();
 
+            if let Some(loop_result) = loop_result(self.tag.as_ref(), cycle_result) {
+                result = loop_result;
+                break;
+            }
+
             contract.validate_maintenance_condition(context)?;
         }
 
-        contract.validate_post_condition(context)?;
+        if !matches!(result, Expression::Return(_)) {
+            contract.validate_post_condition(context)?;
+        }
 
         Ok(result)
     }
@@ -126,8 +179,7 @@ pub struct RangedForLoop {
     pub tag: Option<String>,
     pub statements: Vec<Statement>,
     pub variable: Reference,
-    pub start: Expression,
-    pub end: Expression,
+    pub range: Range,
 }
 
 impl RangedForLoop {
@@ -136,45 +188,51 @@ impl RangedForLoop {
 
         let contract = context.get_contract(self.tag.as_ref());
 
-        context.push_stack();
+        let (start, end, value_type) = match execute_or_propagate!(self.range, context) {
+            Expression::Range(range) => range.bounds()?,
+            other => return Err(anyhow!("Invalid range '{:?}'", other)),
+        };
 
-        let previous_variable_value = context.search_reference(&self.variable).cloned();
+        context.push_stack();
 
-        let start = self.start.execute(context)?;
-        let end = self.end.execute(context)?;
+        let previous_variable_value = context.search_reference(&self.variable)?.cloned();
 
-        let (start, end) =
-            if let (Expression::Integer(start), Expression::Integer(end)) = (&start, &end) {
-                (start.as_usize(), end.as_usize())
-            } else {
-                return Err(anyhow!("Invalid range from '{:?}' to '{:?}'", start, end));
-            };
+        let value = |value: i64| Expression::Integer(Integer::I64(value).with_type_of(value_type));
 
-        context.insert_into_heap(&self.variable, start.into())?;
+        context.insert_into_heap(&self.variable, value(start))?;
         contract.validate_pre_condition(context)?;
 
-        'main: for i in start..end {
-            context.insert_or_update_in_heap(&self.variable, i.into())?;
+        // the value of the variable once the loop is done, which is the end of the range
+        // unless the loop is broken out of
+        let mut last = end;
+
+        for i in start..end {
+            context.insert_or_update_in_heap(&self.variable, value(i))?;
 
             context.push_stack();
+            let cycle_result = execute_statements(&self.statements, context)?;
+            context.pop_stack();
 
-            for statement in &self.statements {
-                result = statement.execute(context)?;
-                if result == Expression::Break {
-                    break 'main;
-                }
+            if let Some(loop_result) = loop_result(self.tag.as_ref(), cycle_result) {
+                result = loop_result;
+                last = i;
+                break;
             }
 
-            context.pop_stack();
-
             contract.validate_maintenance_condition(context)?;
         }
 
+        // a `return` leaves the function along with the loop, which never finishes
+        if matches!(result, Expression::Return(_)) {
+            context.pop_stack();
+            return Ok(result);
+        }
+
         if let Some(previous_variable_value) = previous_variable_value {
             context.insert_or_update_in_heap(&self.variable, previous_variable_value)?;
         }
 
-        context.insert_or_update_in_heap(&self.variable, end.into())?;
+        context.insert_or_update_in_heap(&self.variable, value(last))?;
         contract.validate_post_condition(context)?;
 
         context.pop_stack();
@@ -182,3 +240,118 @@ impl RangedForLoop {
         Ok(result)
     }
 }
+
+/// A `for` loop over the elements of a vector or the values of a range, like
+/// `for (i, x) in v.iter().enumerate()` or `for i in (0..n).rev()`. The variables
+/// bound by the pattern are visible to the contract conditions, with the pre-condition
+/// seeing the first element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IteratorForLoop {
+    pub tag: Option<String>,
+    pub statements: Vec<Statement>,
+    pub pattern: Pattern,
+    pub iterator: Expression,
+}
+
+impl IteratorForLoop {
+    fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
+        let mut result = Expression::Unit;
+
+        let contract = context.get_contract(self.tag.as_ref());
+
+        let items = match execute_or_propagate!(self.iterator, context) {
+            Expression::Vector(items) => items,
+            Expression::Range(range) => range.items()?,
+            other => return Err(anyhow!("Cannot iterate over '{:?}'", other)),
+        };
+
+        match items.first() {
+            Some(first) => {
+                context.push_stack_from(self.bind(first)?);
+                contract.validate_pre_condition(context)?;
+                context.pop_stack();
+            }
+            None => contract.validate_pre_condition(context)?,
+        }
+
+        for item in &items {
+            context.push_stack_from(self.bind(item)?);
+            let cycle_result = execute_statements(&self.statements, context)?;
+
+            if let Some(loop_result) = loop_result(self.tag.as_ref(), cycle_result) {
+                context.pop_stack();
+                result = loop_result;
+                break;
+            }
+
+            contract.validate_maintenance_condition(context)?;
+            context.pop_stack();
+        }
+
+        if !matches!(result, Expression::Return(_)) {
+            contract.validate_post_condition(context)?;
+        }
+
+        Ok(result)
+    }
+
+    fn bind(&self, item: &Expression) -> anyhow::Result<Vec<FunctionArgParamPair>> {
+        let mut bindings = Vec::new();
+        if self.pattern.matches(item, &mut bindings) {
+            Ok(bindings)
+        } else {
+            Err(anyhow!(
+                "Cannot destructure '{:?}' into {:?}",
+                item,
+                self.pattern
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfiniteLoop {
+    pub tag: Option<String>,
+    pub statements: Vec<Statement>,
+}
+
+impl InfiniteLoop {
+    fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
+        let contract = context.get_contract(self.tag.as_ref());
+
+        contract.validate_pre_condition(context)?;
+
+        let result = loop {
+            context.push_stack();
+            let cycle_result = execute_statements(&self.statements, context)?;
+            context.pop_stack();
+
+            if let Some(loop_result) = loop_result(self.tag.as_ref(), cycle_result) {
+                break loop_result;
+            }
+
+            contract.validate_maintenance_condition(context)?;
+        };
+
+        if !matches!(result, Expression::Return(_)) {
+            contract.validate_post_condition(context)?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Decides whether a loop has to stop after a cycle, returning the value the loop
+/// evaluates to if that's the case. An unlabeled `break` or `continue`, or one labeled
+/// with the loop tag, is handled by the loop itself, while any other signal is handed
+/// over to the enclosing loop or function.
+fn loop_result(tag: Option<&String>, cycle_result: Expression) -> Option<Expression> {
+    let targets_loop = |label: &Option<String>| label.is_none() || label.as_ref() == tag;
+
+    match cycle_result {
+        Expression::Break(label, value) if targets_loop(&label) => Some(*value),
+        Expression::Continue(label) if targets_loop(&label) => None,
+        result if result.is_control_flow() => Some(result),
+        _ => None,
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{interpreter::context::Context, Expression};
+use super::{
+    functions::{evaluate_arg, write_back_mutable_args},
+    Reference,
+};
+use crate::{interpreter::context::Context, Expression, Function};
 use anyhow::anyhow;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -10,18 +14,282 @@ pub struct MethodCall {
 
 impl MethodCall {
     pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
-        let expression = self.expression.execute(context)?;
-        execute_method(expression, &self.method, &self.args)
+        // like in Rust, the receiver is evaluated before the arguments. Methods called on
+        // places are executed in place, so that the ones that mutate their receiver (like
+        // `push` or `pop`) update the place
+        match self.expression.to_reference(context)? {
+            Some(reference) => self.execute_on_place(context, reference),
+            None => {
+                let receiver = execute_or_propagate!(self.expression, context);
+                self.execute_on_value(context, receiver)
+            }
+        }
+    }
+
+    fn execute_on_value(
+        &self,
+        context: &mut Context,
+        mut receiver: Expression,
+    ) -> anyhow::Result<Expression> {
+        if let Some(method) = interpreted_method(&receiver, &self.method) {
+            return Ok(self
+                .execute_interpreted_method(context, method, receiver)?
+                .0);
+        }
+
+        let mut args = Vec::with_capacity(self.args.len());
+        for arg in &self.args {
+            args.push(execute_or_propagate!(arg, context));
+        }
+
+        execute_method(&mut receiver, &self.method, args)
+    }
+
+    fn execute_on_place(
+        &self,
+        context: &mut Context,
+        reference: Reference,
+    ) -> anyhow::Result<Expression> {
+        // slices are read as a vector of their elements, which is written back to them
+        // in case the method mutated it
+        if let Reference::Slice(..) = reference {
+            let mut receiver = reference.execute(context)?;
+
+            let mut args = Vec::with_capacity(self.args.len());
+            for arg in &self.args {
+                args.push(execute_or_propagate!(arg, context));
+            }
+
+            let result = execute_method(&mut receiver, &self.method, args)?;
+            context.insert_or_update_in_heap(&reference, receiver)?;
+
+            return Ok(result);
+        }
+
+        let method = context
+            .search_reference(&reference)?
+            .and_then(|receiver| interpreted_method(receiver, &self.method));
+
+        if let Some(method) = method {
+            let receiver = reference.execute(context)?;
+            let (result, receiver) = self.execute_interpreted_method(context, method, receiver)?;
+
+            if let Some(receiver) = receiver {
+                context.insert_or_update_in_heap(&reference, receiver)?;
+            }
+
+            return Ok(result);
+        }
+
+        let mut args = Vec::with_capacity(self.args.len());
+        for arg in &self.args {
+            args.push(execute_or_propagate!(arg, context));
+        }
+
+        let receiver = context
+            .search_reference_mut(&reference)?
+            .ok_or(anyhow!("Unknown reference '{}'", reference))?;
+
+        execute_method(receiver, &self.method, args)
+    }
+
+    /// Executes an interpreted method with the receiver as its `self` argument, returning
+    /// the final value of the receiver as well if the method takes `&mut self`. The other
+    /// arguments taken by `&mut` reference are written back to the caller's places.
+    fn execute_interpreted_method(
+        &self,
+        context: &mut Context,
+        method: Function,
+        receiver: Expression,
+    ) -> anyhow::Result<(Expression, Option<Expression>)> {
+        let mut args = Vec::with_capacity(self.args.len() + 1);
+        let mut places = Vec::with_capacity(self.args.len());
+        args.push(receiver);
+
+        // the receiver is the first argument of the method
+        for (index, expression) in self.args.iter().enumerate() {
+            let (arg, place) = evaluate_arg(context, expression, method.is_mutable_arg(index + 1))?;
+            if arg.is_control_flow() {
+                return Ok((arg, None));
+            }
+
+            args.push(arg);
+            places.push(place);
+        }
+
+        let mut method_context = context.child(method.contracts.clone());
+        let (result, mutable_args) = method.execute_with_mutable_args(&mut method_context, args)?;
+
+        let mut receiver = None;
+        let mut mutable_params = Vec::new();
+
+        for (index, value) in mutable_args {
+            match index {
+                0 => receiver = Some(value),
+                index => mutable_params.push((index - 1, value)),
+            }
+        }
+
+        write_back_mutable_args(context, &places, mutable_params)?;
+
+        Ok((result, receiver))
+    }
+}
+
+/// Looks up a method of the type of the receiver that's defined in an `impl` block with
+/// `define_method_builders`.
+fn interpreted_method(receiver: &Expression, method: &str) -> Option<Function> {
+    match receiver {
+        Expression::Struct(structure) => structure
+            .methods
+            .and_then(|methods| methods(method))
+            .map(|builder| builder()),
+        _ => None,
     }
 }
 
 fn execute_method(
-    expression: Expression,
+    expression: &mut Expression,
     method: &str,
-    args: &Vec<Expression>,
+    args: Vec<Expression>,
 ) -> anyhow::Result<Expression> {
-    match (&expression, method, args) {
-        (Expression::Vector(vec), "len", _) => Ok(vec.len().into()),
+    match (&mut *expression, method, args.as_slice()) {
+        (Expression::Vector(vec), "len", []) => Ok(vec.len().into()),
+        (Expression::Vector(vec), "is_empty", []) => Ok(vec.is_empty().into()),
+        (Expression::Vector(vec), "push", [value]) => {
+            vec.push(value.clone());
+            Ok(Expression::Unit)
+        }
+        (Expression::Vector(vec), "pop", []) => Ok(vec.pop().into()),
+        (Expression::Vector(vec), "swap", [Expression::Integer(i), Expression::Integer(j)]) => {
+            let (i, j) = (i.as_usize(), j.as_usize());
+
+            if i >= vec.len() || j >= vec.len() {
+                return Err(anyhow!(
+                    "Cannot swap indices {} and {} of a vector of length {}",
+                    i,
+                    j,
+                    vec.len()
+                ));
+            }
+
+            vec.swap(i, j);
+            Ok(Expression::Unit)
+        }
+        (Expression::Vector(vec), "split_at", [Expression::Integer(mid)]) => {
+            let mid = mid.as_usize();
+
+            if mid > vec.len() {
+                return Err(anyhow!(
+                    "Cannot split a vector of length {} at {}",
+                    vec.len(),
+                    mid
+                ));
+            }
+
+            let (left, right) = vec.split_at(mid);
+            Ok(Expression::Tuple(vec![
+                Expression::Vector(left.to_vec()),
+                Expression::Vector(right.to_vec()),
+            ]))
+        }
+        (Expression::Vector(vec), "first", []) => Ok(vec.first().cloned().into()),
+        (Expression::Vector(vec), "last", []) => Ok(vec.last().cloned().into()),
+        // iterators are represented by the vector of the elements they yield
+        (Expression::Vector(vec), "iter" | "into_iter" | "copied" | "cloned", []) => {
+            Ok(Expression::Vector(vec.clone()))
+        }
+        (Expression::Vector(vec), "rev", []) => {
+            Ok(Expression::Vector(vec.iter().rev().cloned().collect()))
+        }
+        (Expression::Vector(vec), "enumerate", []) => Ok(Expression::Vector(
+            vec.iter()
+                .enumerate()
+                .map(|(index, element)| Expression::Tuple(vec![index.into(), element.clone()]))
+                .collect(),
+        )),
+        (Expression::Vector(vec), "step_by", [Expression::Integer(step)]) => {
+            match step.as_usize() {
+                0 => Err(anyhow!("The step of 'step_by' must not be zero")),
+                step => Ok(Expression::Vector(
+                    vec.iter().step_by(step).cloned().collect(),
+                )),
+            }
+        }
+        (Expression::Range(range), "contains", [Expression::Integer(value)]) => {
+            Ok(range.contains(value)?.into())
+        }
+        (Expression::Range(range), "len", []) => {
+            let (start, end, _) = range.bounds()?;
+            Ok(((end - start).max(0) as usize).into())
+        }
+        (Expression::Range(range), _, _) => {
+            execute_method(&mut Expression::Vector(range.items()?), method, args)
+        }
+        (Expression::Integer(lhs), "checked_add", [Expression::Integer(rhs)]) => {
+            let (lhs, rhs) = lhs.unify_operands(*rhs)?;
+            Ok(lhs.checked_add(rhs).into())
+        }
+        (Expression::Integer(lhs), "checked_sub", [Expression::Integer(rhs)]) => {
+            let (lhs, rhs) = lhs.unify_operands(*rhs)?;
+            Ok(lhs.checked_sub(rhs).into())
+        }
+        (Expression::Integer(lhs), "checked_mul", [Expression::Integer(rhs)]) => {
+            let (lhs, rhs) = lhs.unify_operands(*rhs)?;
+            Ok(lhs.checked_mul(rhs).into())
+        }
+        (Expression::Integer(lhs), "checked_div", [Expression::Integer(rhs)]) => {
+            let (lhs, rhs) = lhs.unify_operands(*rhs)?;
+            Ok(lhs.checked_div(rhs).into())
+        }
+        (Expression::String(string), "len", []) => Ok(string.len().into()),
+        (Expression::String(string), "is_empty", []) => Ok(string.is_empty().into()),
+        (Expression::String(string), "chars", []) => Ok(string.chars().collect::<Vec<_>>().into()),
+        (Expression::String(string), "as_bytes", []) => Ok(string.as_bytes().to_vec().into()),
+        (Expression::String(string), "push", [Expression::Char(character)]) => {
+            string.push(*character);
+            Ok(Expression::Unit)
+        }
+        (Expression::String(string), "push_str", [Expression::String(other)]) => {
+            string.push_str(other);
+            Ok(Expression::Unit)
+        }
+        (Expression::String(string), "contains", [Expression::String(pattern)]) => {
+            Ok(string.contains(pattern.as_str()).into())
+        }
+        (Expression::String(string), "contains", [Expression::Char(pattern)]) => {
+            Ok(string.contains(*pattern).into())
+        }
+        (Expression::String(string), "starts_with", [Expression::String(pattern)]) => {
+            Ok(string.starts_with(pattern.as_str()).into())
+        }
+        (Expression::String(string), "starts_with", [Expression::Char(pattern)]) => {
+            Ok(string.starts_with(*pattern).into())
+        }
+        (Expression::Integer(value), "to_string", []) => Ok(value.to_string().into()),
+        (Expression::Char(value), "to_string", []) => Ok(value.to_string().into()),
+        (Expression::String(value), "to_string", []) => Ok(value.clone().into()),
+        (Expression::Float(value), "abs", []) => Ok(Expression::Float(value.abs())),
+        (Expression::Float(value), "sqrt", []) => Ok(Expression::Float(value.sqrt())),
+        (Expression::Optional(value), "is_some", []) => Ok(value.is_some().into()),
+        (Expression::Optional(value), "is_none", []) => Ok(value.is_none().into()),
+        (Expression::Optional(Some(value)), "unwrap", []) => Ok(*value.clone()),
+        (Expression::Optional(value), "unwrap_or", [default]) => Ok(value
+            .as_ref()
+            .map_or_else(|| default.clone(), |value| *value.clone())),
+        (Expression::Optional(value), "copied" | "cloned", []) => {
+            Ok(Expression::Optional(value.clone()))
+        }
+        (Expression::Optional(value), "take", []) => Ok(Expression::Optional(value.take())),
+        (Expression::Result(value), "is_ok", []) => Ok(value.is_ok().into()),
+        (Expression::Result(value), "is_err", []) => Ok(value.is_err().into()),
+        (Expression::Result(Ok(value)), "unwrap", []) => Ok(*value.clone()),
+        (Expression::Result(value), "unwrap_or", [default]) => Ok(value
+            .as_ref()
+            .map_or_else(|_| default.clone(), |value| *value.clone())),
+        (Expression::Result(value), "ok", []) => Ok(Expression::Optional(value.clone().ok())),
+        (Expression::Vector(vec), "collect", []) => Ok(Expression::Vector(vec.clone())),
+        (expression, "clone" | "to_owned" | "to_vec", []) => Ok(expression.clone()),
         _ => Err(anyhow!(
             "Invalid method '{}' for value '{:?}'",
             method,
@@ -9,8 +9,12 @@ pub enum Operation {
     Sub(Expression, Expression),
     Div(Expression, Expression),
     Mul(Expression, Expression),
+    Rem(Expression, Expression),
     BitAnd(Expression, Expression),
     BitOr(Expression, Expression),
+    BitXor(Expression, Expression),
+    Shl(Expression, Expression),
+    Shr(Expression, Expression),
     And(Expression, Expression),
     Or(Expression, Expression),
     Eq(Expression, Expression),
@@ -21,24 +25,123 @@ pub enum Operation {
     Gte(Expression, Expression),
 }
 
+/// The prefix `-` and `!` operators.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnaryOperation {
+    Neg(Expression),
+    Not(Expression),
+}
+
+impl UnaryOperation {
+    pub fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        match self {
+            Self::Neg(operand) => neg(execute_or_propagate!(operand, context)),
+            Self::Not(operand) => not(execute_or_propagate!(operand, context)),
+        }
+    }
+}
+
 impl Operation {
     pub fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
-        Ok(match self {
-            Self::Add(lhs, rhs) => add(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::Sub(lhs, rhs) => sub(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::Mul(lhs, rhs) => mul(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::Div(lhs, rhs) => div(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::BitAnd(lhs, rhs) => bitand(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::BitOr(lhs, rhs) => bitor(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::And(lhs, rhs) => and(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::Or(lhs, rhs) => or(lhs.execute(context)?, rhs.execute(context)?)?,
-            Self::Eq(lhs, rhs) => eq(&lhs.execute(context)?, &rhs.execute(context)?)?,
-            Self::Ne(lhs, rhs) => ne(&lhs.execute(context)?, &rhs.execute(context)?)?,
-            Self::Lt(lhs, rhs) => lt(&lhs.execute(context)?, &rhs.execute(context)?)?,
-            Self::Lte(lhs, rhs) => lte(&lhs.execute(context)?, &rhs.execute(context)?)?,
-            Self::Gt(lhs, rhs) => gt(&lhs.execute(context)?, &rhs.execute(context)?)?,
-            Self::Gte(lhs, rhs) => gte(&lhs.execute(context)?, &rhs.execute(context)?)?,
-        })
+        let (lhs, rhs) = self.operands();
+        let lhs = execute_or_propagate!(lhs, context);
+
+        // `&&` and `||` short-circuit, so guards like `i < v.len() && v[i] == x` are safe
+        match (self, &lhs) {
+            (Self::And(..), Expression::Bool(false)) | (Self::Or(..), Expression::Bool(true)) => {
+                return Ok(lhs)
+            }
+            _ => {}
+        }
+
+        let rhs = execute_or_propagate!(rhs, context);
+
+        self.apply(lhs, rhs)
+    }
+
+    /// Applies a binary operation to operands that were already evaluated.
+    pub(crate) fn apply(&self, lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
+        // the amount of a shift can be of any type and bitwise operations keep the bits
+        // of an `i32` operand, while the other integer operands have to fit in one type
+        let (lhs, rhs) = match (self, lhs, rhs) {
+            (
+                Self::Shl(..)
+                | Self::Shr(..)
+                | Self::BitAnd(..)
+                | Self::BitOr(..)
+                | Self::BitXor(..),
+                lhs,
+                rhs,
+            ) => (lhs, rhs),
+            (_, Expression::Integer(lhs), Expression::Integer(rhs)) => {
+                let (lhs, rhs) = lhs.unify_operands(rhs)?;
+                (lhs.into(), rhs.into())
+            }
+            (_, lhs, rhs) => (lhs, rhs),
+        };
+
+        match self {
+            Self::Add(..) => add(lhs, rhs),
+            Self::Sub(..) => sub(lhs, rhs),
+            Self::Mul(..) => mul(lhs, rhs),
+            Self::Div(..) => div(lhs, rhs),
+            Self::Rem(..) => rem(lhs, rhs),
+            Self::BitAnd(..) => bitand(lhs, rhs),
+            Self::BitOr(..) => bitor(lhs, rhs),
+            Self::BitXor(..) => bitxor(lhs, rhs),
+            Self::Shl(..) => shl(lhs, rhs),
+            Self::Shr(..) => shr(lhs, rhs),
+            Self::And(..) => and(lhs, rhs),
+            Self::Or(..) => or(lhs, rhs),
+            Self::Eq(..) => eq(&lhs, &rhs),
+            Self::Ne(..) => ne(&lhs, &rhs),
+            Self::Lt(..) => lt(&lhs, &rhs),
+            Self::Lte(..) => lte(&lhs, &rhs),
+            Self::Gt(..) => gt(&lhs, &rhs),
+            Self::Gte(..) => gte(&lhs, &rhs),
+        }
+    }
+
+    pub(crate) fn operands(&self) -> (&Expression, &Expression) {
+        match self {
+            Self::Add(lhs, rhs)
+            | Self::Sub(lhs, rhs)
+            | Self::Mul(lhs, rhs)
+            | Self::Div(lhs, rhs)
+            | Self::BitAnd(lhs, rhs)
+            | Self::Rem(lhs, rhs)
+            | Self::BitOr(lhs, rhs)
+            | Self::BitXor(lhs, rhs)
+            | Self::Shl(lhs, rhs)
+            | Self::Shr(lhs, rhs)
+            | Self::And(lhs, rhs)
+            | Self::Or(lhs, rhs)
+            | Self::Eq(lhs, rhs)
+            | Self::Ne(lhs, rhs)
+            | Self::Lt(lhs, rhs)
+            | Self::Lte(lhs, rhs)
+            | Self::Gt(lhs, rhs)
+            | Self::Gte(lhs, rhs) => (lhs, rhs),
+        }
+    }
+}
+
+fn neg(operand: Expression) -> anyhow::Result<Expression> {
+    match operand {
+        Expression::Integer(integer) => integer
+            .negate()
+            .map(Expression::Integer)
+            .ok_or(anyhow!("Cannot negate unsigned integer {:?}", integer)),
+        Expression::Float(float) => Ok(Expression::Float(-float)),
+        operand => Err(anyhow!("Unsupported negation of {:?}", operand)),
+    }
+}
+
+fn not(operand: Expression) -> anyhow::Result<Expression> {
+    match operand {
+        Expression::Bool(bool) => Ok(Expression::Bool(!bool)),
+        Expression::Integer(integer) => Ok(Expression::Integer(!integer)),
+        operand => Err(anyhow!("Unsupported NOT of {:?}", operand)),
     }
 }
 
@@ -99,6 +202,21 @@ fn div(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
     }
 }
 
+fn rem(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
+    match (lhs, rhs) {
+        (Expression::Integer(lhs), Expression::Integer(rhs)) => lhs
+            .checked_rem(rhs)
+            .map(Expression::Integer)
+            .ok_or(anyhow!("Invalid remainder of {:?} by {:?}", lhs, rhs)),
+        (Expression::Float(lhs), Expression::Float(rhs)) => Ok(Expression::Float(lhs % rhs)),
+        (lhs, rhs) => Err(anyhow!(
+            "Unsupported remainder between {:?} and {:?}",
+            lhs,
+            rhs
+        )),
+    }
+}
+
 fn bitand(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
     match (lhs, rhs) {
         (Expression::Integer(lhs), Expression::Integer(rhs)) => Ok(Expression::Integer(lhs & rhs)),
@@ -121,6 +239,46 @@ fn bitor(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
     }
 }
 
+fn bitxor(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
+    match (lhs, rhs) {
+        (Expression::Integer(lhs), Expression::Integer(rhs)) => Ok(Expression::Integer(lhs ^ rhs)),
+        (Expression::Bool(lhs), Expression::Bool(rhs)) => Ok(Expression::Bool(lhs ^ rhs)),
+        (lhs, rhs) => Err(anyhow!(
+            "Unsupported bitwise XOR between {:?} and {:?}",
+            lhs,
+            rhs
+        )),
+    }
+}
+
+fn shl(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
+    match (lhs, rhs) {
+        (Expression::Integer(lhs), Expression::Integer(rhs)) => lhs
+            .checked_shl(rhs)
+            .map(Expression::Integer)
+            .ok_or(anyhow!("Invalid left shift of {:?} by {:?}", lhs, rhs)),
+        (lhs, rhs) => Err(anyhow!(
+            "Unsupported left shift between {:?} and {:?}",
+            lhs,
+            rhs
+        )),
+    }
+}
+
+fn shr(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
+    match (lhs, rhs) {
+        (Expression::Integer(lhs), Expression::Integer(rhs)) => lhs
+            .checked_shr(rhs)
+            .map(Expression::Integer)
+            .ok_or(anyhow!("Invalid right shift of {:?} by {:?}", lhs, rhs)),
+        (lhs, rhs) => Err(anyhow!(
+            "Unsupported right shift between {:?} and {:?}",
+            lhs,
+            rhs
+        )),
+    }
+}
+
 fn and(lhs: Expression, rhs: Expression) -> anyhow::Result<Expression> {
     match (lhs, rhs) {
         (Expression::Bool(lhs), Expression::Bool(rhs)) => Ok(Expression::Bool(lhs && rhs)),
@@ -140,6 +298,9 @@ fn eq(lhs: &Expression, rhs: &Expression) -> anyhow::Result<Expression> {
         (Expression::Integer(lhs), Expression::Integer(rhs)) => Ok(Expression::Bool(*lhs == *rhs)),
         (Expression::Float(lhs), Expression::Float(rhs)) => Ok(Expression::Bool(*lhs == *rhs)),
         (Expression::Bool(lhs), Expression::Bool(rhs)) => Ok(Expression::Bool(*lhs == *rhs)),
+        (Expression::Char(lhs), Expression::Char(rhs)) => Ok(Expression::Bool(*lhs == *rhs)),
+        (Expression::String(lhs), Expression::String(rhs)) => Ok(Expression::Bool(*lhs == *rhs)),
+        (Expression::Enum(lhs), Expression::Enum(rhs)) => Ok(Expression::Bool(*lhs == *rhs)),
         (lhs, rhs) => Err(anyhow!(
             "Unsupported equals between {:?} and {:?}",
             lhs,
@@ -158,7 +319,9 @@ fn lt(lhs: &Expression, rhs: &Expression) -> anyhow::Result<Expression> {
     match (lhs, rhs) {
         (Expression::Integer(lhs), Expression::Integer(rhs)) => Ok(Expression::Bool(*lhs < *rhs)),
         (Expression::Float(lhs), Expression::Float(rhs)) => Ok(Expression::Bool(*lhs < *rhs)),
-        (Expression::Bool(lhs), Expression::Bool(rhs)) => Ok(Expression::Bool(*lhs < *rhs)),
+        (Expression::Bool(lhs), Expression::Bool(rhs)) => Ok(Expression::Bool(!*lhs && *rhs)),
+        (Expression::Char(lhs), Expression::Char(rhs)) => Ok(Expression::Bool(*lhs < *rhs)),
+        (Expression::String(lhs), Expression::String(rhs)) => Ok(Expression::Bool(*lhs < *rhs)),
         (lhs, rhs) => Err(anyhow!(
             "Unsupported less than between {:?} and {:?}",
             lhs,
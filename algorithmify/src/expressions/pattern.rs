@@ -0,0 +1,93 @@
+use crate::Expression;
+
+use super::functions::FunctionArgParamPair;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Wildcard,
+    Binding(String),
+    Literal(Expression),
+    Range(Expression, Expression),
+    Or(Vec<Pattern>),
+    Tuple(Vec<Pattern>),
+    Some(Box<Pattern>),
+    None,
+    Ok(Box<Pattern>),
+    Err(Box<Pattern>),
+    /// An enum variant given by the enum name, the variant name and the patterns of
+    /// its payload.
+    Enum(String, String, Vec<Pattern>),
+}
+
+impl Pattern {
+    /// Checks if the value matches the pattern, adding the variables bound by the
+    /// pattern to `bindings` when it does.
+    pub(crate) fn matches(
+        &self,
+        value: &Expression,
+        bindings: &mut Vec<FunctionArgParamPair>,
+    ) -> bool {
+        match self {
+            Self::Wildcard => true,
+            Self::Binding(variable) => {
+                bindings.push((variable.clone(), value.clone()));
+                true
+            }
+            Self::Literal(literal) => literal == value,
+            Self::Range(start, end) => is_in_range(value, start, end),
+            Self::Tuple(patterns) => match value {
+                Expression::Tuple(elements) if elements.len() == patterns.len() => patterns
+                    .iter()
+                    .zip(elements)
+                    .all(|(pattern, element)| pattern.matches(element, bindings)),
+                _ => false,
+            },
+            Self::Some(pattern) => match value {
+                Expression::Optional(Some(value)) => pattern.matches(value, bindings),
+                _ => false,
+            },
+            Self::None => matches!(value, Expression::Optional(None)),
+            Self::Ok(pattern) => match value {
+                Expression::Result(Ok(value)) => pattern.matches(value, bindings),
+                _ => false,
+            },
+            Self::Err(pattern) => match value {
+                Expression::Result(Err(error)) => pattern.matches(error, bindings),
+                _ => false,
+            },
+            Self::Enum(name, variant, patterns) => match value {
+                Expression::Enum(enumeration)
+                    if &enumeration.name == name
+                        && &enumeration.variant == variant
+                        && enumeration.payload.len() == patterns.len() =>
+                {
+                    patterns
+                        .iter()
+                        .zip(&enumeration.payload)
+                        .all(|(pattern, element)| pattern.matches(element, bindings))
+                }
+                _ => false,
+            },
+            Self::Or(patterns) => patterns.iter().any(|pattern| {
+                let bindings_count = bindings.len();
+                let matches = pattern.matches(value, bindings);
+                if !matches {
+                    bindings.truncate(bindings_count);
+                }
+                matches
+            }),
+        }
+    }
+}
+
+fn is_in_range(value: &Expression, start: &Expression, end: &Expression) -> bool {
+    match (value, start, end) {
+        (Expression::Integer(value), Expression::Integer(start), Expression::Integer(end)) => {
+            start <= value && value <= end
+        }
+        (Expression::Char(value), Expression::Char(start), Expression::Char(end)) => {
+            start <= value && value <= end
+        }
+        _ => false,
+    }
+}
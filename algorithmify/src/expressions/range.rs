@@ -0,0 +1,99 @@
+use anyhow::anyhow;
+
+use crate::{interpreter::context::Context, Expression};
+
+use super::Integer;
+
+/// A range like `a..b` or `a..=b`, whose bounds can be left open like in `..b`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    pub start: Option<Expression>,
+    pub end: Option<Expression>,
+    pub inclusive: bool,
+}
+
+impl Range {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let start = match &self.start {
+            Some(start) => Some(execute_or_propagate!(start, context)),
+            None => None,
+        };
+
+        let end = match &self.end {
+            Some(end) => Some(execute_or_propagate!(end, context)),
+            None => None,
+        };
+
+        Ok(Expression::Range(Box::new(Range {
+            start,
+            end,
+            inclusive: self.inclusive,
+        })))
+    }
+
+    /// The bounds of an evaluated range, with the end made exclusive, along with an
+    /// integer of the type the values of the range have. Like with the arithmetic
+    /// operators, an unsuffixed bound takes the type of the other one, so `0..v.len()`
+    /// goes through `usize` values.
+    pub(crate) fn bounds(&self) -> anyhow::Result<(i64, i64, Integer)> {
+        match (&self.start, &self.end) {
+            (Some(Expression::Integer(start)), Some(Expression::Integer(end))) => {
+                let (start, end) = start.unify_operands(*end)?;
+                let end = end.as_i64() + self.inclusive as i64;
+
+                Ok((start.as_i64(), end, start))
+            }
+            _ => Err(anyhow!("Invalid range '{:?}'", self)),
+        }
+    }
+
+    /// Whether the value is within the bounds of an evaluated range, comparing the
+    /// integers by value whatever their types are.
+    pub(crate) fn contains(&self, value: &Integer) -> anyhow::Result<bool> {
+        match (&self.start, &self.end) {
+            (Some(Expression::Integer(start)), Some(Expression::Integer(end))) => {
+                let before_end = if self.inclusive {
+                    value <= end
+                } else {
+                    value < end
+                };
+
+                Ok(start <= value && before_end)
+            }
+            _ => Err(anyhow!("Invalid range '{:?}'", self)),
+        }
+    }
+
+    /// The values the range goes through, which is how ranges are iterated.
+    pub(crate) fn items(&self) -> anyhow::Result<Vec<Expression>> {
+        let (start, end, value_type) = self.bounds()?;
+
+        Ok((start..end)
+            .map(|value| Integer::I64(value).with_type_of(value_type).into())
+            .collect())
+    }
+
+    /// The bounds of an evaluated range used to slice a vector of the given length,
+    /// where open bounds extend to the start and end of the vector.
+    pub(crate) fn slice_bounds(&self, length: usize) -> anyhow::Result<(usize, usize)> {
+        let bound = |bound: &Option<Expression>, default: usize| match bound {
+            Some(Expression::Integer(bound)) => Ok(bound.as_usize()),
+            None => Ok(default),
+            Some(_) => Err(anyhow!("Invalid range '{:?}'", self)),
+        };
+
+        let start = bound(&self.start, 0)?;
+        let end = bound(&self.end, length)? + (self.inclusive as usize);
+
+        if start > end || end > length {
+            return Err(anyhow!(
+                "Range {}..{} out of bounds of a slice of length {}",
+                start,
+                end,
+                length
+            ));
+        }
+
+        Ok((start, end))
+    }
+}
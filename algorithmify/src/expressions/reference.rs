@@ -3,18 +3,35 @@ use std::fmt::Display;
 
 use crate::{interpreter::context::Context, Expression};
 
+/// A place in the heap, which is either a variable or an element or field nested at
+/// any depth inside of one, like `grid[i][j]` or `edges[k].weight`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Reference {
     Variable(String),
-    IndexedAccess(String, usize),
+    IndexedAccess(Box<Reference>, usize),
+    FieldAccess(Box<Reference>, String),
+    /// The elements of a vector between two bounds, like `v[lo..hi]`. Slices are read
+    /// as a vector of those elements, and writing a vector of the same length to them
+    /// replaces the elements of the sliced vector.
+    Slice(Box<Reference>, usize, usize),
 }
 
 impl Reference {
     pub(crate) fn execute(&self, context: &mut Context) -> Result<Expression, anyhow::Error> {
-        if let Some(expression) = context.search_reference(self) {
-            Ok(expression.clone())
-        } else {
-            return Err(anyhow!("Unknown reference '{}'", self));
+        if let Reference::Slice(reference, start, end) = self {
+            return match context.search_reference(reference)? {
+                Some(Expression::Vector(vector)) => {
+                    Ok(Expression::Vector(vector[*start..*end].to_vec()))
+                }
+                _ => Err(anyhow!("Unknown reference '{}'", self)),
+            };
+        }
+
+        match context.search_reference(self)? {
+            // a variable bound to a `&mut` borrow reads the place it borrows
+            Some(Expression::Reference(view)) => view.clone().execute(context),
+            Some(expression) => Ok(expression.clone()),
+            None => Err(anyhow!("Unknown reference '{}'", self)),
         }
     }
 }
@@ -23,47 +40,168 @@ impl Display for Reference {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Reference::Variable(variable) => write!(f, "{}", variable),
-            Reference::IndexedAccess(variable, index) => write!(f, "{}[{}]", variable, index),
+            Reference::IndexedAccess(reference, index) => write!(f, "{}[{}]", reference, index),
+            Reference::FieldAccess(reference, field) => write!(f, "{}.{}", reference, field),
+            Reference::Slice(reference, start, end) => {
+                write!(f, "{}[{}..{}]", reference, start, end)
+            }
+        }
+    }
+}
+
+impl Expression {
+    /// Resolves the place an expression like `v[i][j]` or `a.b` points to, if it's
+    /// one, evaluating the indices along the way.
+    pub(crate) fn to_reference(&self, context: &mut Context) -> anyhow::Result<Option<Reference>> {
+        match self {
+            Expression::Reference(reference) => Ok(Some(context.resolve_view(reference)?)),
+            Expression::IndexedAccessExpression(expression) => expression.to_reference(context),
+            Expression::FieldAccessExpression(expression) => expression.to_reference(context),
+            _ => Ok(None),
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct IndexedAccessExpression {
-    pub variable: String,
+    pub expression: Box<Expression>,
     pub index: Box<Expression>,
 }
 
 impl IndexedAccessExpression {
     pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
-        let index = self.index.execute(context)?;
-        if let Expression::Integer(index) = index {
-            let reference = Expression::Reference(Reference::IndexedAccess(
-                self.variable.clone(),
-                index.as_usize(),
-            ));
+        // elements of variables are read in place, without copying the whole vector
+        if let Some(reference) = self.to_reference(context)? {
+            return reference.execute(context);
+        }
 
-            reference.execute(context)
-        } else {
-            Err(anyhow!(
+        let expression = execute_or_propagate!(self.expression, context);
+
+        let vector = match &expression {
+            Expression::Vector(vector) => vector,
+            _ => return Err(anyhow!("Cannot index into '{:?}'", expression)),
+        };
+
+        match self.index.execute(context)? {
+            Expression::Integer(index) => vector.get(index.as_usize()).cloned().ok_or(anyhow!(
+                "Index {} not found in '{:?}'",
+                index.as_usize(),
+                expression
+            )),
+            Expression::Range(range) => {
+                let (start, end) = range.slice_bounds(vector.len())?;
+                Ok(Expression::Vector(vector[start..end].to_vec()))
+            }
+            index => Err(anyhow!(
                 "{:?} does not resolve to a valid index expression",
                 index
-            ))
+            )),
         }
     }
 
-    pub(crate) fn to_reference(&self, context: &mut Context) -> anyhow::Result<Expression> {
-        let index = self.index.execute(context)?;
-        if let Expression::Integer(index) = index {
-            Ok(Expression::Reference(Reference::IndexedAccess(
-                self.variable.clone(),
-                index.as_usize(),
-            )))
-        } else {
-            Err(anyhow!(
+    /// Resolves the place of an element or a slice. Indices into slices are relative to
+    /// the slice, and are checked against its bounds before being made relative to the
+    /// sliced vector, so slices only ever appear at the end of a reference.
+    pub(crate) fn to_reference(&self, context: &mut Context) -> anyhow::Result<Option<Reference>> {
+        let reference = match self.expression.to_reference(context)? {
+            Some(reference) => reference,
+            None => return Ok(None),
+        };
+
+        let (reference, offset, length) = match reference {
+            Reference::Slice(reference, start, end) => (*reference, start, Some(end - start)),
+            reference => (reference, 0, None),
+        };
+
+        match self.index.execute(context)? {
+            Expression::Integer(index) => {
+                let index = index.as_usize();
+
+                if let Some(length) = length.filter(|length| index >= *length) {
+                    return Err(anyhow!(
+                        "Index {} out of bounds of a slice of length {}",
+                        index,
+                        length
+                    ));
+                }
+
+                Ok(Some(Reference::IndexedAccess(
+                    Box::new(reference),
+                    offset + index,
+                )))
+            }
+            Expression::Range(range) => {
+                let length = match length {
+                    Some(length) => length,
+                    None => match context.search_reference(&reference)? {
+                        Some(Expression::Vector(vector)) => vector.len(),
+                        _ => return Err(anyhow!("Cannot slice '{}'", reference)),
+                    },
+                };
+
+                let (start, end) = range.slice_bounds(length)?;
+
+                Ok(Some(Reference::Slice(
+                    Box::new(reference),
+                    offset + start,
+                    offset + end,
+                )))
+            }
+            index => Err(anyhow!(
                 "{:?} does not resolve to a valid index expression",
                 index
-            ))
+            )),
         }
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldAccessExpression {
+    pub expression: Box<Expression>,
+    pub field: String,
+}
+
+impl FieldAccessExpression {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let expression = execute_or_propagate!(self.expression, context);
+
+        field(&expression, &self.field).cloned().ok_or(anyhow!(
+            "Field '{}' not found in '{:?}'",
+            self.field,
+            expression
+        ))
+    }
+
+    pub(crate) fn to_reference(&self, context: &mut Context) -> anyhow::Result<Option<Reference>> {
+        Ok(self
+            .expression
+            .to_reference(context)?
+            .map(|reference| Reference::FieldAccess(Box::new(reference), self.field.clone())))
+    }
+}
+
+/// Looks up a field of a struct, or an element of a tuple by its position.
+pub(crate) fn field<'a>(expression: &'a Expression, field: &str) -> Option<&'a Expression> {
+    match expression {
+        Expression::Tuple(elements) => field
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| elements.get(index)),
+        Expression::Struct(structure) => structure.field(field),
+        _ => None,
+    }
+}
+
+pub(crate) fn field_mut<'a>(
+    expression: &'a mut Expression,
+    field: &str,
+) -> Option<&'a mut Expression> {
+    match expression {
+        Expression::Tuple(elements) => field
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| elements.get_mut(index)),
+        Expression::Struct(structure) => structure.field_mut(field),
+        _ => None,
+    }
+}
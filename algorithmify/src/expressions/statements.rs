@@ -1,11 +1,25 @@
-use super::{reference::Reference, Expression, IndexedAccessExpression};
+use super::{reference::Reference, Assertion, Expression, Operation, Pattern, Type};
 use crate::interpreter::context::Context;
 use anyhow::anyhow;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Statement {
     Assignment(Reference, Expression),
-    IndexedAssigment(IndexedAccessExpression, Expression),
+    /// A `let` binding with a type annotation, which gives the numbers in the value the
+    /// annotated types.
+    TypedAssignment(Reference, Type, Expression),
+    /// An assignment to a place like `grid[i][j]` or `edges[k].weight`.
+    PlaceAssignment(Expression, Expression),
+    /// A `let` binding of a `&mut` borrow like `let s = &mut v[a..b]`. When the borrowed
+    /// expression is a place, the variable is a view of it, through which reads and
+    /// writes reach the place.
+    Borrow(Reference, Expression),
+    Destructuring(Pattern, Expression),
+    /// An assignment like `v[i] += x`, given as the operation between the place being
+    /// assigned and the value.
+    CompoundAssignment(Operation),
+    /// An `assert!`-family macro, checked in place.
+    Assertion(Assertion),
     Expression(Expression),
 }
 
@@ -13,20 +27,88 @@ impl Statement {
     pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
         match self {
             Self::Assignment(reference, expression) => {
-                let result = expression.execute(context)?;
+                let result = execute_or_propagate!(expression, context);
                 context.insert_or_update_in_heap(reference, result)?;
                 Ok(Expression::Unit)
             }
-            Self::IndexedAssigment(key, value) => match key.to_reference(context) {
-                Ok(Expression::Reference(reference)) => {
-                    let result = value.execute(context)?;
-                    context.insert_or_update_in_heap(&reference, result)?;
-                    Ok(Expression::Unit)
+            Self::TypedAssignment(reference, value_type, expression) => {
+                let result = execute_or_propagate!(expression, context);
+                context.insert_or_update_in_heap(reference, value_type.conform(result)?)?;
+                Ok(Expression::Unit)
+            }
+            Self::PlaceAssignment(place, value) => {
+                // like in compound assignments, the value is evaluated before the place
+                let result = execute_or_propagate!(value, context);
+
+                let reference = place
+                    .to_reference(context)?
+                    .ok_or(anyhow!("Cannot assign to expression {:?}", place))?;
+                context.insert_or_update_in_heap(&reference, result)?;
+                Ok(Expression::Unit)
+            }
+            Self::Borrow(reference, expression) => {
+                let result = match expression.to_reference(context)? {
+                    Some(place) => Expression::Reference(place),
+                    None => execute_or_propagate!(expression, context),
+                };
+                context.insert_or_update_in_heap(reference, result)?;
+                Ok(Expression::Unit)
+            }
+            Self::Destructuring(pattern, expression) => {
+                let result = execute_or_propagate!(expression, context);
+
+                let mut bindings = Vec::new();
+                if !pattern.matches(&result, &mut bindings) {
+                    return Err(anyhow!(
+                        "Cannot destructure '{:?}' into {:?}",
+                        result,
+                        pattern
+                    ));
+                }
+
+                for (variable, value) in bindings {
+                    context.insert_or_update_in_heap(&Reference::Variable(variable), value)?;
                 }
-                Ok(_) => Err(anyhow!("Cannot assign to expression {:?}", key)),
-                err @ Err(_) => err,
-            },
+
+                Ok(Expression::Unit)
+            }
+            Self::CompoundAssignment(operation) => {
+                let (place, value) = operation.operands();
+
+                // like Rust does for primitives, the value is evaluated before the place,
+                // whose index is only evaluated once
+                let value = execute_or_propagate!(value, context);
+
+                let reference = place
+                    .to_reference(context)?
+                    .ok_or(anyhow!("Cannot assign to expression {:?}", place))?;
+
+                let current = reference.execute(context)?;
+                let result = operation.apply(current, value)?;
+                context.insert_or_update_in_heap(&reference, result)?;
+
+                Ok(Expression::Unit)
+            }
+            Self::Assertion(assertion) => assertion.execute(context),
             Self::Expression(expression) => expression.execute(context),
         }
     }
 }
+
+/// Executes the statements in order and returns the result of the last one, stopping
+/// early if one of them yields a control flow signal like `break` or `return`.
+pub(crate) fn execute_statements(
+    statements: &[Statement],
+    context: &mut Context,
+) -> anyhow::Result<Expression> {
+    let mut result = Expression::Unit;
+
+    for statement in statements {
+        result = statement.execute(context)?;
+        if result.is_control_flow() {
+            break;
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,86 @@
+use anyhow::anyhow;
+
+use crate::{interpreter::context::Context, Expression};
+
+use super::FunctionBuilder;
+
+/// Looks up the builder of a method by its name.
+pub type MethodResolver = fn(&str) -> Option<FunctionBuilder>;
+
+/// Resolves the methods of the types without interpreted methods, which is none of
+/// them. `define_method_builders` generates an inherent `__method_builder` function for
+/// the type, which takes precedence over this one.
+pub trait MethodBuilders {
+    fn __method_builder(_method: &str) -> Option<FunctionBuilder> {
+        None
+    }
+}
+
+impl<T> MethodBuilders for T {}
+
+#[derive(Clone)]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<(String, Expression)>,
+    /// The interpreted methods of the type of the struct.
+    pub methods: Option<MethodResolver>,
+}
+
+impl Struct {
+    pub(crate) fn execute(&self, context: &mut Context) -> anyhow::Result<Expression> {
+        let mut fields = Vec::with_capacity(self.fields.len());
+        for (field, expression) in &self.fields {
+            fields.push((field.clone(), execute_or_propagate!(expression, context)));
+        }
+
+        Ok(Expression::Struct(Struct {
+            name: self.name.clone(),
+            fields,
+            methods: self.methods,
+        }))
+    }
+
+    pub fn field(&self, field: &str) -> Option<&Expression> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, expression)| expression)
+    }
+
+    pub fn field_mut(&mut self, field: &str) -> Option<&mut Expression> {
+        self.fields
+            .iter_mut()
+            .find(|(name, _)| name == field)
+            .map(|(_, expression)| expression)
+    }
+
+    /// Moves the value out of a field, used when converting the struct back into a
+    /// native value.
+    pub fn take_field(&mut self, field: &str) -> anyhow::Result<Expression> {
+        self.fields
+            .iter_mut()
+            .find(|(name, _)| name == field)
+            .map(|(_, expression)| std::mem::replace(expression, Expression::Unit))
+            .ok_or_else(|| anyhow!("Field '{}' not found in struct '{}'", field, self.name))
+    }
+}
+
+impl std::fmt::Debug for Struct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Struct")
+            .field("name", &self.name)
+            .field("fields", &self.fields)
+            .finish()
+    }
+}
+
+impl PartialEq for Struct {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.fields.len() == other.fields.len()
+            && self
+                .fields
+                .iter()
+                .all(|(field, expression)| other.field(field) == Some(expression))
+    }
+}
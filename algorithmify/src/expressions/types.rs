@@ -0,0 +1,169 @@
+use anyhow::anyhow;
+
+use crate::Expression;
+
+use super::{Float, Integer};
+
+/// The type of a variable, parameter or return value as written in the function
+/// signature or `let` annotation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    I8,
+    I16,
+    I32,
+    I64,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+    F32,
+    F64,
+    Bool,
+    Char,
+    String,
+    Unit,
+    Vector(Box<Type>),
+    Tuple(Vec<Type>),
+    Option(Box<Type>),
+    Result(Box<Type>, Box<Type>),
+    /// A closure type like `impl Fn(usize) -> bool`, given by its parameter and return
+    /// types.
+    Function(Vec<Type>, Box<Type>),
+    /// A user-defined type or generic parameter, along with its generic arguments.
+    Named(String, Vec<Type>),
+    /// A `&mut` reference, like the one of `&mut self`, whose final value is handed
+    /// back to the caller.
+    MutableReference(Box<Type>),
+}
+
+impl Type {
+    /// Gives the numbers within a value the type the native code infers for them, as
+    /// unsuffixed literals are always interpreted as `i32` or `f64`. Only those are
+    /// retyped, and only when their value fits the declared type.
+    pub(crate) fn conform(&self, expression: Expression) -> anyhow::Result<Expression> {
+        Ok(match (self, expression) {
+            (Self::MutableReference(value_type), expression) => value_type.conform(expression)?,
+            (Self::Vector(element_type), Expression::Vector(elements)) => Expression::Vector(
+                elements
+                    .into_iter()
+                    .map(|element| element_type.conform(element))
+                    .collect::<anyhow::Result<_>>()?,
+            ),
+            (Self::Tuple(types), Expression::Tuple(elements)) if types.len() == elements.len() => {
+                Expression::Tuple(
+                    types
+                        .iter()
+                        .zip(elements)
+                        .map(|(element_type, element)| element_type.conform(element))
+                        .collect::<anyhow::Result<_>>()?,
+                )
+            }
+            (Self::Option(value_type), Expression::Optional(Some(value))) => {
+                Expression::Optional(Some(Box::new(value_type.conform(*value)?)))
+            }
+            (Self::Result(value_type, _), Expression::Result(Ok(value))) => {
+                Expression::Result(Ok(Box::new(value_type.conform(*value)?)))
+            }
+            (Self::Result(_, error_type), Expression::Result(Err(error))) => {
+                Expression::Result(Err(Box::new(error_type.conform(*error)?)))
+            }
+            (Self::Named(..), expression) => expression,
+            (_, expression @ (Expression::Integer(_) | Expression::Float(_))) => {
+                self.conform_number(&expression).ok_or(anyhow!(
+                    "Expected a value of type {:?}, got '{:?}'",
+                    self,
+                    expression
+                ))?
+            }
+            (_, expression) => expression,
+        })
+    }
+
+    /// Whether a value can be bound to a variable of this type. Numbers have to be of
+    /// this exact type or be unsuffixed literals that fit it, generic parameters accept
+    /// any value and other named types only accept structs and enums of that name.
+    pub(crate) fn accepts(&self, expression: &Expression, generics: &[String]) -> bool {
+        match (self, expression) {
+            (Self::MutableReference(value_type), expression) => {
+                value_type.accepts(expression, generics)
+            }
+            (Self::Bool, Expression::Bool(_))
+            | (Self::Char, Expression::Char(_))
+            | (Self::String, Expression::String(_))
+            | (Self::Unit, Expression::Unit) => true,
+            (Self::Vector(element_type), Expression::Vector(elements)) => elements
+                .iter()
+                .all(|element| element_type.accepts(element, generics)),
+            (Self::Tuple(types), Expression::Tuple(elements)) => {
+                types.len() == elements.len()
+                    && types
+                        .iter()
+                        .zip(elements)
+                        .all(|(element_type, element)| element_type.accepts(element, generics))
+            }
+            (Self::Option(value_type), Expression::Optional(value)) => value
+                .as_ref()
+                .is_none_or(|value| value_type.accepts(value, generics)),
+            (Self::Result(value_type, _), Expression::Result(Ok(value))) => {
+                value_type.accepts(value, generics)
+            }
+            (Self::Result(_, error_type), Expression::Result(Err(error))) => {
+                error_type.accepts(error, generics)
+            }
+            (Self::Function(..), Expression::Closure(_)) => true,
+            (Self::Named(name, _), _) if generics.contains(name) => true,
+            (Self::Named(name, _), Expression::Struct(structure)) => &structure.name == name,
+            (Self::Named(name, _), Expression::Enum(enumeration)) => &enumeration.name == name,
+            (Self::Named(..), _) => false,
+            (_, Expression::Integer(_) | Expression::Float(_)) => {
+                self.conform_number(expression).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// A number of exactly this type, or an unsuffixed literal retyped to it if it fits.
+    fn conform_number(&self, expression: &Expression) -> Option<Expression> {
+        let integer = match expression {
+            Expression::Integer(integer) => *integer,
+            Expression::Float(Float::F64(value)) if *self == Self::F32 => {
+                return Some(Expression::Float(Float::F32(*value as f32)))
+            }
+            Expression::Float(float) => {
+                return matches!(
+                    (self, float),
+                    (Self::F32, Float::F32(_)) | (Self::F64, Float::F64(_))
+                )
+                .then(|| expression.clone())
+            }
+            _ => return None,
+        };
+
+        let conformed = match (self, integer) {
+            (Self::I8, Integer::I8(_))
+            | (Self::I16, Integer::I16(_))
+            | (Self::I32, Integer::I32(_))
+            | (Self::I64, Integer::I64(_))
+            | (Self::Isize, Integer::Isize(_))
+            | (Self::U8, Integer::U8(_))
+            | (Self::U16, Integer::U16(_))
+            | (Self::U32, Integer::U32(_))
+            | (Self::U64, Integer::U64(_))
+            | (Self::Usize, Integer::Usize(_)) => integer,
+            (Self::I8, Integer::I32(value)) => Integer::I8(value.try_into().ok()?),
+            (Self::I16, Integer::I32(value)) => Integer::I16(value.try_into().ok()?),
+            (Self::I64, Integer::I32(value)) => Integer::I64(value.into()),
+            (Self::Isize, Integer::I32(value)) => Integer::Isize(value.try_into().ok()?),
+            (Self::U8, Integer::I32(value)) => Integer::U8(value.try_into().ok()?),
+            (Self::U16, Integer::I32(value)) => Integer::U16(value.try_into().ok()?),
+            (Self::U32, Integer::I32(value)) => Integer::U32(value.try_into().ok()?),
+            (Self::U64, Integer::I32(value)) => Integer::U64(value.try_into().ok()?),
+            (Self::Usize, Integer::I32(value)) => Integer::Usize(value.try_into().ok()?),
+            _ => return None,
+        };
+
+        Some(Expression::Integer(conformed))
+    }
+}
@@ -15,16 +15,57 @@ impl Interpreter {
         }
     }
 
+    /// The text printed so far by the functions run on this interpreter, with `print!`,
+    /// `println!`, `eprint!` or `eprintln!`, in the order it was printed.
+    pub fn output(&self) -> String {
+        self.root_context.output()
+    }
+
+    /// Runs the function on this interpreter, keeping what it prints in its output.
+    pub fn run(&mut self, function: Function) -> anyhow::Result<Expression> {
+        let mut context = self.root_context.child(function.contracts.clone());
+        function.execute(&mut context, vec![])
+    }
+
+    /// Runs the function with the given arguments on this interpreter, keeping what it
+    /// prints in its output. See `execute_function_with_args` for what it returns.
+    pub fn run_with_args(
+        &mut self,
+        function: Function,
+        expressions: Vec<Expression>,
+    ) -> anyhow::Result<Expression> {
+        let mut context = self.root_context.child(function.contracts.clone());
+        let (result, mutable_args) =
+            function.execute_with_mutable_args(&mut context, expressions)?;
+
+        if result != Expression::Unit || mutable_args.is_empty() {
+            return Ok(result);
+        }
+
+        let mut mutable_args = mutable_args
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>();
+
+        if mutable_args.len() == 1 {
+            Ok(mutable_args.remove(0))
+        } else {
+            Ok(Expression::Tuple(mutable_args))
+        }
+    }
+
     pub fn execute_function(function: Function) -> anyhow::Result<Expression> {
-        let mut interpreter = Interpreter::new(function.contracts.clone());
-        function.execute(&mut interpreter.root_context, vec![])
+        Interpreter::new(function.contracts.clone()).run(function)
     }
 
+    /// Executes the function with the given arguments. Procedures that return nothing
+    /// but take arguments by `&mut` reference, like `fn sort(v: &mut Vec<i32>)`, return
+    /// the final values of those arguments instead: the value itself if there's only one,
+    /// or a tuple of them otherwise.
     pub fn execute_function_with_args(
         function: Function,
         expressions: Vec<Expression>,
     ) -> anyhow::Result<Expression> {
-        let mut interpreter = Interpreter::new(function.contracts.clone());
-        function.execute(&mut interpreter.root_context, expressions)
+        Interpreter::new(function.contracts.clone()).run_with_args(function, expressions)
     }
 }
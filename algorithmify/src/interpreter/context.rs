@@ -1,9 +1,12 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use anyhow::anyhow;
 
 use crate::expressions::{
-    functions::FunctionArgParamPair, loops::Contract, reference::Reference, Expression,
+    functions::FunctionArgParamPair,
+    loops::Contract,
+    reference::{field, field_mut, Reference},
+    Expression,
 };
 
 pub type ContractMap = HashMap<String, Contract>;
@@ -11,6 +14,9 @@ pub type ContractMap = HashMap<String, Contract>;
 pub struct Context {
     stack: Vec<HashMap<String, Expression>>,
     contracts: ContractMap,
+    /// The text printed so far, shared by the contexts of the functions called from
+    /// the one being interpreted.
+    output: Rc<RefCell<String>>,
 }
 
 impl Context {
@@ -18,9 +24,29 @@ impl Context {
         Self {
             stack: Vec::new(),
             contracts,
+            output: Default::default(),
         }
     }
 
+    /// The context of a function or closure called from this one, which prints to the
+    /// same output.
+    pub(crate) fn child(&self, contracts: ContractMap) -> Self {
+        Self {
+            stack: Vec::new(),
+            contracts,
+            output: self.output.clone(),
+        }
+    }
+
+    /// Appends text printed with `print!`-family macros to the output.
+    pub(crate) fn write_output(&self, text: &str) {
+        self.output.borrow_mut().push_str(text);
+    }
+
+    pub(crate) fn output(&self) -> String {
+        self.output.borrow().clone()
+    }
+
     fn search_expression(&self, key: &str) -> Option<&Expression> {
         for map in self.stack.iter().rev() {
             if let Some(expression) = map.get(key) {
@@ -39,17 +65,57 @@ impl Context {
         None
     }
 
-    pub(crate) fn search_reference(&self, reference: &Reference) -> Option<&Expression> {
-        match reference {
+    /// Finds the value at a place. Slices aren't stored as such, so they can't be
+    /// borrowed in place and have to be read with `Reference::execute` instead.
+    pub(crate) fn search_reference(
+        &self,
+        reference: &Reference,
+    ) -> anyhow::Result<Option<&Expression>> {
+        Ok(match reference {
             Reference::Variable(variable) => self.search_expression(variable),
-            Reference::IndexedAccess(variable, index) => {
-                self.search_expression(variable)
-                    .and_then(|expression| match expression {
-                        Expression::Vector(vector) => Some(&vector[*index]),
-                        _ => None,
-                    })
+            Reference::IndexedAccess(reference, index) => self
+                .search_reference(reference)?
+                .and_then(|expression| match expression {
+                    Expression::Vector(vector) => vector.get(*index),
+                    _ => None,
+                }),
+            Reference::FieldAccess(reference, name) => self
+                .search_reference(reference)?
+                .and_then(|expression| field(expression, name)),
+            Reference::Slice(..) => {
+                return Err(anyhow!("Cannot borrow slice '{}' in place", reference))
             }
-        }
+        })
+    }
+
+    pub(crate) fn search_reference_mut(
+        &mut self,
+        reference: &Reference,
+    ) -> anyhow::Result<Option<&mut Expression>> {
+        Ok(match reference {
+            Reference::Variable(variable) => self.search_expression_mut(variable),
+            Reference::IndexedAccess(reference, index) => self
+                .search_reference_mut(reference)?
+                .and_then(|expression| match expression {
+                    Expression::Vector(vector) => vector.get_mut(*index),
+                    _ => None,
+                }),
+            Reference::FieldAccess(reference, name) => self
+                .search_reference_mut(reference)?
+                .and_then(|expression| field_mut(expression, name)),
+            Reference::Slice(..) => {
+                return Err(anyhow!("Cannot borrow slice '{}' in place", reference))
+            }
+        })
+    }
+
+    /// Follows a variable bound to a `&mut` borrow, like `s` in `let s = &mut v[a..b]`,
+    /// to the place it borrows.
+    pub(crate) fn resolve_view(&self, reference: &Reference) -> anyhow::Result<Reference> {
+        Ok(match self.search_reference(reference)? {
+            Some(Expression::Reference(view)) => view.clone(),
+            _ => reference.clone(),
+        })
     }
 
     pub(crate) fn push_stack(&mut self) {
@@ -84,16 +150,27 @@ impl Context {
                         .insert(variable.clone(), expression);
                 }
             }
-            Reference::IndexedAccess(variable, index) => {
-                if let Some(vector_expression) = self.search_expression_mut(variable) {
-                    match vector_expression {
-                        Expression::Vector(vector) => vector[*index] = expression.clone(),
-                        _ => return Err(anyhow!("{} is not a vector", variable)),
+            Reference::Slice(sliced, start, end) => {
+                match (self.search_reference_mut(sliced)?, expression) {
+                    (Some(Expression::Vector(vector)), Expression::Vector(elements))
+                        if elements.len() == end - start =>
+                    {
+                        vector.splice(*start..*end, elements);
+                    }
+                    (_, expression) => {
+                        return Err(anyhow!(
+                            "Cannot write '{:?}' into '{}'",
+                            expression,
+                            reference
+                        ))
                     }
-                } else {
-                    return Err(anyhow!("{} not found", variable));
                 }
             }
+            _ => {
+                *self
+                    .search_reference_mut(reference)?
+                    .ok_or(anyhow!("Unknown reference '{}'", reference))? = expression;
+            }
         }
 
         Ok(())
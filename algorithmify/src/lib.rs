@@ -4,3 +4,7 @@ pub mod interpreter;
 pub use expressions::functions::Function;
 pub use expressions::Expression;
 pub use interpreter::Interpreter;
+
+// used by the code generated when deriving `Expression`
+#[doc(hidden)]
+pub use anyhow;
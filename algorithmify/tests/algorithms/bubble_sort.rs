@@ -0,0 +1,59 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[define_function_builder]
+fn bubble_sort_maintenance_condition<T: PartialOrd>(i: usize, vector: Vec<T>) -> bool {
+    let mut valid = true;
+    let sorted_start = vector.len() - i - 1;
+
+    // the last i + 1 elements are the largest ones, and they are already sorted
+    for j in sorted_start + 1..vector.len() {
+        if vector[j - 1] > vector[j] {
+            valid = false;
+        }
+    }
+
+    valid
+}
+
+#[define_function_builder {
+    main: {
+        maintenance_condition: bubble_sort_maintenance_condition
+    }
+}]
+fn bubble_sort<T>(mut vector: Vec<T>) -> Vec<T>
+where
+    T: PartialEq + PartialOrd + Copy,
+{
+    'main: for i in 0..vector.len() {
+        let mut sorted = true;
+
+        for j in 0..vector.len() - i - 1 {
+            if vector[j] > vector[j + 1] {
+                let temp = vector[j];
+                vector[j] = vector[j + 1];
+                vector[j + 1] = temp;
+                sorted = false;
+            }
+        }
+
+        // no swaps means the vector is already sorted
+        if sorted {
+            break;
+        }
+    }
+
+    vector
+}
+
+#[test]
+pub fn test_bubble_sort() {
+    let expression = Interpreter::execute_function_with_args(
+        bubble_sort__function_builder(),
+        vec![vec![3usize, 12, 5, 6, 1].into()],
+    )
+    .unwrap();
+
+    assert_eq!(bubble_sort(vec![3, 12, 5, 6, 1]), vec![1, 3, 5, 6, 12]);
+    assert_eq!(expression, vec![1usize, 3, 5, 6, 12].into());
+}
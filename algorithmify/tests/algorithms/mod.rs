@@ -1,2 +1,2 @@
-
+mod bubble_sort;
 mod insertion_sort;
@@ -0,0 +1,93 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[define_function_builder]
+fn insertion_sort(mut v: Vec<i32>) -> Vec<i32> {
+    let length = v.len();
+
+    for i in 1..v.len() {
+        let mut j = i;
+
+        while j > 0 && v[j - 1] > v[j] {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+
+        for k in 1..=i {
+            debug_assert!(v[k - 1] <= v[k], "the prefix must be sorted");
+        }
+    }
+
+    assert_eq!(v.len(), length);
+    v
+}
+
+#[test]
+pub fn test_passing_assertions() {
+    let expression = Interpreter::execute_function_with_args(
+        insertion_sort__function_builder(),
+        vec![vec![3, 1, 2].into()],
+    )
+    .unwrap();
+
+    assert_eq!(insertion_sort(vec![3, 1, 2]), vec![1, 2, 3]);
+    assert_eq!(expression, vec![1, 2, 3].into());
+}
+
+#[test]
+pub fn test_failed_assertions() {
+    #[define_function_builder]
+    fn checked_division(a: i32, b: i32) -> i32 {
+        assert!(b != 0);
+        assert_ne!(a, b);
+        a / b
+    }
+
+    #[define_function_builder]
+    fn checked_sum(v: Vec<i32>, expected: i32) -> i32 {
+        let mut sum = 0;
+
+        for x in v.iter() {
+            sum += *x;
+        }
+
+        assert_eq!(sum, expected, "wrong sum");
+        sum
+    }
+
+    let error = Interpreter::execute_function_with_args(
+        checked_division__function_builder(),
+        vec![4.into(), 0.into()],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Assertion 'assert!(b != 0)' failed: left: 'Integer(I32(0))', right: 'Integer(I32(0))'"
+    );
+
+    let error = Interpreter::execute_function_with_args(
+        checked_division__function_builder(),
+        vec![4.into(), 4.into()],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Assertion 'assert_ne!(a, b)' failed: left: 'Integer(I32(4))', right: 'Integer(I32(4))'"
+    );
+
+    let error = Interpreter::execute_function_with_args(
+        checked_sum__function_builder(),
+        vec![vec![1, 2].into(), 4.into()],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Assertion 'assert_eq!(sum, expected, \"wrong sum\")' failed: left: 'Integer(I32(3))', right: 'Integer(I32(4))'"
+    );
+
+    assert_eq!(checked_division(9, 3), 3);
+    assert_eq!(checked_sum(vec![1, 2], 3), 3);
+}
@@ -0,0 +1,60 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_integer_casts() {
+    #[define_function_builder]
+    fn integer_casts(value: i32) -> (u8, i64, usize) {
+        (value as u8, -value as i64 * 2, value as u32 as usize)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        integer_casts__function_builder(),
+        vec![300.into()],
+    )
+    .unwrap();
+
+    assert_eq!(integer_casts(300), (44, -600, 300));
+    assert_eq!(expression, (44u8, -600i64, 300usize).into());
+}
+
+#[test]
+pub fn test_float_casts() {
+    #[define_function_builder]
+    fn average(vector: Vec<u32>) -> (f64, i32) {
+        let mut sum = 0;
+        for i in 0..vector.len() {
+            sum += vector[i];
+        }
+        let average = sum as f64 / vector.len() as f64;
+        (average, -average as i32)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        average__function_builder(),
+        vec![vec![1u32, 2, 4].into()],
+    )
+    .unwrap();
+
+    let expected = average(vec![1, 2, 4]);
+    assert_eq!(expected.1, -2);
+    assert_eq!(expression, expected.into());
+}
+
+#[test]
+pub fn test_char_and_bool_casts() {
+    #[define_function_builder]
+    fn caesar(character: char, shift: u8, letters: u8) -> (char, u8) {
+        let offset = (character as u8 - b'a' + shift) % letters;
+        ((b'a' + offset) as char, (offset > 10) as u8)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        caesar__function_builder(),
+        vec!['y'.into(), 3u8.into(), 26u8.into()],
+    )
+    .unwrap();
+
+    assert_eq!(caesar('y', 3, 26), ('b', 0));
+    assert_eq!(expression, ('b', 0u8).into());
+}
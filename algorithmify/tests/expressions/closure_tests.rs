@@ -0,0 +1,154 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[define_function_builder]
+fn sort_by(mut v: Vec<i32>, before: impl Fn(i32, i32) -> bool) -> Vec<i32> {
+    for i in 1..v.len() {
+        let mut j = i;
+
+        while j > 0 && before(v[j], v[j - 1]) {
+            let temp = v[j];
+            v[j] = v[j - 1];
+            v[j - 1] = temp;
+            j -= 1;
+        }
+    }
+
+    v
+}
+
+#[define_function_builder]
+fn fold<F>(v: Vec<i32>, init: i64, f: F) -> i64
+where
+    F: Fn(i64, i32) -> i64,
+{
+    let mut acc = init;
+
+    for x in v.iter() {
+        acc = f(acc, *x);
+    }
+
+    acc
+}
+
+#[define_function_builder]
+fn binary_search_by<P: Fn(&(usize, char)) -> bool>(v: Vec<(usize, char)>, is_before: P) -> usize {
+    let mut low = 0;
+    let mut high = v.len();
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        if is_before(&v[middle]) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+#[test]
+pub fn test_closure_captures_by_value() {
+    #[define_function_builder]
+    fn add_offset() -> i32 {
+        let mut offset = 10;
+        let add = move |x| x + offset;
+
+        offset = 100;
+
+        add(5) + offset
+    }
+
+    let expression = Interpreter::execute_function(add_offset__function_builder()).unwrap();
+
+    assert_eq!(add_offset(), 115);
+    assert_eq!(expression, 115.into());
+}
+
+#[test]
+pub fn test_closure_passed_to_function() {
+    #[define_function_builder]
+    fn sort_descending(v: Vec<i32>) -> Vec<i32> {
+        sort_by(v, |a, b| a > b)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        sort_descending__function_builder(),
+        vec![vec![3, 9, -2, 7].into()],
+    )
+    .unwrap();
+
+    assert_eq!(sort_descending(vec![3, 9, -2, 7]), vec![9, 7, 3, -2]);
+    assert_eq!(expression, vec![9, 7, 3, -2].into());
+}
+
+#[test]
+pub fn test_closure_with_generic_bound() {
+    #[define_function_builder]
+    fn weighted_total(v: Vec<i32>, weight: i64) -> i64 {
+        fold(v, 0, move |acc, x| {
+            if x < 0 {
+                return acc;
+            }
+
+            acc + x as i64 * weight
+        })
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        weighted_total__function_builder(),
+        vec![vec![1, -5, 2, 3].into(), 10i64.into()],
+    )
+    .unwrap();
+
+    assert_eq!(weighted_total(vec![1, -5, 2, 3], 10), 60);
+    assert_eq!(expression, 60i64.into());
+}
+
+#[test]
+pub fn test_closure_with_pattern_params() {
+    #[define_function_builder]
+    fn first_at_least(v: Vec<(usize, char)>, key: usize) -> usize {
+        binary_search_by(v, |&(k, _)| k < key)
+    }
+
+    let v = vec![(1, 'a'), (4, 'b'), (4, 'c'), (9, 'd')];
+
+    let expression = Interpreter::execute_function_with_args(
+        first_at_least__function_builder(),
+        vec![v.clone().into(), 4usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(first_at_least(v, 4), 1);
+    assert_eq!(expression, 1usize.into());
+}
+
+#[test]
+pub fn test_closure_goes_out_of_scope() {
+    #[define_function_builder]
+    fn twice(x: i32) -> i32 {
+        x * 2
+    }
+
+    #[define_function_builder]
+    fn shadow_in_block(x: i32) -> i32 {
+        let inner = {
+            let twice = |y: i32| y + 1;
+            twice(x)
+        };
+
+        inner + twice(x)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        shadow_in_block__function_builder(),
+        vec![3.into()],
+    )
+    .unwrap();
+
+    assert_eq!(shadow_in_block(3), 10);
+    assert_eq!(expression, 10.into());
+}
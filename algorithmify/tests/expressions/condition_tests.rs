@@ -63,3 +63,127 @@ pub fn test_else_if_condition() {
     assert_eq!(else_if_condition(), 3);
     assert_eq!(expression, 3.into());
 }
+
+#[test]
+pub fn test_match_literal_and_wildcard() {
+    #[define_function_builder]
+    fn match_literal(value: i32) -> i32 {
+        match value {
+            1 => 10,
+            2 | 3 => 20,
+            -1 => 30,
+            _ => 40,
+        }
+    }
+
+    for value in [1, 2, 3, -1, 7] {
+        let expression = Interpreter::execute_function_with_args(
+            match_literal__function_builder(),
+            vec![value.into()],
+        )
+        .unwrap();
+
+        assert_eq!(expression, match_literal(value).into());
+    }
+}
+
+#[test]
+pub fn test_match_range_and_guard() {
+    #[define_function_builder]
+    fn match_range(value: i32) -> i32 {
+        let mut result = 0;
+
+        match value {
+            0..=9 => {
+                result = 1;
+            }
+            n if n >= 100 => {
+                result = n * 2;
+            }
+            10..=50 | 60..=70 => {
+                result = 3;
+            }
+            _ => {}
+        }
+
+        result
+    }
+
+    for value in [0, 5, 9, 10, 65, 55, 100, 120] {
+        let expression = Interpreter::execute_function_with_args(
+            match_range__function_builder(),
+            vec![value.into()],
+        )
+        .unwrap();
+
+        assert_eq!(expression, match_range(value).into());
+    }
+}
+
+#[test]
+pub fn test_match_bool() {
+    #[define_function_builder]
+    fn match_bool(value: i32) -> i32 {
+        match value > 5 {
+            true => 1,
+            false => 0,
+        }
+    }
+
+    let expression =
+        Interpreter::execute_function_with_args(match_bool__function_builder(), vec![7.into()])
+            .unwrap();
+
+    assert_eq!(match_bool(7), 1);
+    assert_eq!(expression, 1.into());
+}
+
+#[test]
+pub fn test_match_char() {
+    #[define_function_builder]
+    fn match_char(value: char) -> i32 {
+        match value {
+            'a' | 'e' | 'i' | 'o' | 'u' => 1,
+            'a'..='z' => 2,
+            _ => 3,
+        }
+    }
+
+    for value in ['a', 'u', 'k', 'Z'] {
+        let expression = Interpreter::execute_function_with_args(
+            match_char__function_builder(),
+            vec![value.into()],
+        )
+        .unwrap();
+
+        assert_eq!(expression, match_char(value).into());
+    }
+}
+
+#[test]
+pub fn test_match_guard_propagates_try() {
+    #[define_function_builder]
+    fn above_limit(value: Option<i32>, limit: Option<i32>) -> Option<i32> {
+        match value {
+            Some(x) if x > limit? => Some(x),
+            _ => None,
+        }
+    }
+
+    let cases = [
+        (Some(7), Some(5)),
+        (Some(3), Some(5)),
+        (Some(7), None),
+        (None, Some(5)),
+    ];
+
+    for (value, limit) in cases {
+        let expression = Interpreter::execute_function_with_args(
+            above_limit__function_builder(),
+            vec![value.into(), limit.into()],
+        )
+        .unwrap();
+
+        assert_eq!(expression, above_limit(value, limit).into());
+    }
+}
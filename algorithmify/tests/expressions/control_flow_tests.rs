@@ -0,0 +1,159 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_return() {
+    #[allow(clippy::needless_return)]
+    #[define_function_builder]
+    fn early_return() -> i32 {
+        let a = 1;
+        return a + 1;
+    }
+
+    let expression = Interpreter::execute_function(early_return__function_builder()).unwrap();
+
+    assert_eq!(early_return(), 2);
+    assert_eq!(expression, 2.into());
+}
+
+#[test]
+pub fn test_return_from_loop() {
+    #[define_function_builder]
+    fn linear_search(vector: Vec<usize>, key: usize) -> usize {
+        for i in 0..vector.len() {
+            if vector[i] == key {
+                return i;
+            }
+        }
+
+        vector.len()
+    }
+
+    let vector = vec![4usize, 8, 15, 16, 23, 42];
+
+    let found = Interpreter::execute_function_with_args(
+        linear_search__function_builder(),
+        vec![vector.clone().into(), 16usize.into()],
+    )
+    .unwrap();
+    let not_found = Interpreter::execute_function_with_args(
+        linear_search__function_builder(),
+        vec![vector.clone().into(), 5usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(linear_search(vector.clone(), 16), 3);
+    assert_eq!(found, 3usize.into());
+    assert_eq!(linear_search(vector, 5), 6);
+    assert_eq!(not_found, 6usize.into());
+}
+
+#[test]
+pub fn test_return_from_nested_loops() {
+    #[define_function_builder]
+    fn nested_return() -> i32 {
+        let mut i = 0;
+
+        while i < 10 {
+            {
+                for j in 0..10 {
+                    if i * j == 12 {
+                        return i;
+                    }
+                }
+            }
+
+            i = i + 1;
+        }
+
+        100
+    }
+
+    let expression = Interpreter::execute_function(nested_return__function_builder()).unwrap();
+
+    assert_eq!(nested_return(), 2);
+    assert_eq!(expression, 2.into());
+}
+
+#[define_function_builder]
+fn search_post_condition(i: usize) -> bool {
+    i == 10
+}
+
+#[define_function_builder {
+    main: {
+        post_condition: search_post_condition,
+    }
+}]
+fn search_with_contract(target: usize) -> usize {
+    'main: for i in 0..10 {
+        if i == target {
+            return i;
+        }
+    }
+
+    0
+}
+
+#[test]
+pub fn test_return_skips_post_condition() {
+    // the loop is left with `i == 3`, which the post-condition would reject
+    let expression = Interpreter::execute_function_with_args(
+        search_with_contract__function_builder(),
+        vec![3usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(search_with_contract(3), 3);
+    assert_eq!(expression, 3usize.into());
+
+    // while a loop that runs to the end is checked with `i == 10`
+    let expression = Interpreter::execute_function_with_args(
+        search_with_contract__function_builder(),
+        vec![20usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(search_with_contract(20), 0);
+    assert_eq!(expression, 0usize.into());
+}
+
+#[define_function_builder]
+fn count_post_condition(count: usize) -> bool {
+    count == 3
+}
+
+#[define_function_builder {
+    main: {
+        post_condition: count_post_condition,
+    }
+}]
+fn position_with_contract(items: Vec<i32>, target: i32) -> usize {
+    let mut count = 0;
+
+    'main: for x in items.iter() {
+        if *x == target {
+            return count;
+        }
+        count += 1;
+    }
+
+    count
+}
+
+#[test]
+pub fn test_return_skips_iterator_post_condition() {
+    let execute = |target: i32| {
+        Interpreter::execute_function_with_args(
+            position_with_contract__function_builder(),
+            vec![vec![1, 2, 3].into(), target.into()],
+        )
+        .unwrap()
+    };
+
+    assert_eq!(position_with_contract(vec![1, 2, 3], 2), 1);
+    assert_eq!(execute(2), 1usize.into());
+
+    assert_eq!(position_with_contract(vec![1, 2, 3], 9), 3);
+    assert_eq!(execute(9), 3usize.into());
+}
@@ -0,0 +1,201 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::{define_function_builder, define_method_builders};
+
+#[derive(Debug, Clone, Copy, PartialEq, algorithmify_macros::Expression)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+pub enum Token {
+    Num(i32),
+    Add,
+    Mul,
+}
+
+#[test]
+pub fn test_unit_variants() {
+    #[define_function_builder]
+    fn next_color(color: Color) -> Color {
+        match color {
+            Color::Red => Color::Green,
+            Color::Green => Color::Blue,
+            Color::Blue => Color::Red,
+        }
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        next_color__function_builder(),
+        vec![Color::Green.into()],
+    )
+    .unwrap();
+
+    assert_eq!(next_color(Color::Green), Color::Blue);
+    assert_eq!(expression, Color::Blue.into());
+    assert_eq!(Color::try_from(expression).unwrap(), Color::Blue);
+}
+
+#[test]
+pub fn test_variant_equality() {
+    #[define_function_builder]
+    fn is_red(color: Color) -> bool {
+        color == Color::Red
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        is_red__function_builder(),
+        vec![Color::Red.into()],
+    )
+    .unwrap();
+
+    assert_eq!(is_red(Color::Red), true);
+    assert_eq!(expression, true.into());
+}
+
+#[test]
+pub fn test_tuple_variants() {
+    #[define_function_builder]
+    fn evaluate_rpn(tokens: Vec<Token>) -> Option<i32> {
+        let mut stack = vec![];
+
+        for i in 0..tokens.len() {
+            match tokens[i] {
+                Token::Num(n) => stack.push(n),
+                Token::Add | Token::Mul => {
+                    let rhs = stack.pop()?;
+                    let lhs = stack.pop()?;
+                    let result = match tokens[i] {
+                        Token::Add => lhs + rhs,
+                        _ => lhs * rhs,
+                    };
+                    stack.push(result);
+                }
+            }
+        }
+
+        stack.pop()
+    }
+
+    let tokens = vec![
+        Token::Num(2),
+        Token::Num(3),
+        Token::Add,
+        Token::Num(4),
+        Token::Mul,
+    ];
+
+    let expression = Interpreter::execute_function_with_args(
+        evaluate_rpn__function_builder(),
+        vec![tokens.clone().into()],
+    )
+    .unwrap();
+
+    assert_eq!(evaluate_rpn(tokens), Some(20));
+    assert_eq!(expression, Some(20).into());
+}
+
+#[test]
+pub fn test_variant_constructor() {
+    #[define_function_builder]
+    fn tokenize(digits: Vec<i32>) -> Vec<Token> {
+        let mut tokens = vec![];
+
+        for i in 0..digits.len() {
+            if i > 0 {
+                tokens.push(Token::Add);
+            }
+            tokens.push(Token::Num(digits[i] * 2));
+        }
+
+        tokens
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        tokenize__function_builder(),
+        vec![vec![1, 2].into()],
+    )
+    .unwrap();
+
+    let expected = vec![Token::Num(2), Token::Add, Token::Num(4)];
+
+    assert_eq!(tokenize(vec![1, 2]), expected);
+    assert_eq!(Vec::<Token>::try_from(expression).unwrap(), expected);
+}
+
+#[define_method_builders]
+impl Token {
+    const NONE: i32 = -1;
+
+    fn is_operator(token: Token) -> bool {
+        match token {
+            Self::Add | Self::Mul => true,
+            Self::Num(_) => false,
+        }
+    }
+
+    fn operator_or_none(token: Token) -> i32 {
+        if token == Self::Add || token == Self::Mul {
+            1
+        } else {
+            Self::NONE
+        }
+    }
+}
+
+#[test]
+pub fn test_self_variants() {
+    #[define_function_builder]
+    fn count_operators(tokens: Vec<Token>) -> usize {
+        let mut count = 0;
+
+        for i in 0..tokens.len() {
+            if Token::is_operator(tokens[i].clone()) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    let tokens = vec![Token::Num(2), Token::Num(3), Token::Add, Token::Mul];
+
+    let expression = Interpreter::execute_function_with_args(
+        count_operators__function_builder(),
+        vec![tokens.clone().into()],
+    )
+    .unwrap();
+
+    assert_eq!(count_operators(tokens), 2);
+    assert_eq!(expression, 2usize.into());
+}
+
+#[test]
+pub fn test_associated_constants() {
+    #[define_function_builder]
+    fn classify(token: Token) -> i32 {
+        match Token::operator_or_none(token) {
+            Token::NONE => 0,
+            n => n,
+        }
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        classify__function_builder(),
+        vec![Token::Add.into()],
+    )
+    .unwrap();
+
+    assert_eq!(classify(Token::Add), 1);
+    assert_eq!(expression, 1.into());
+
+    let expression = Interpreter::execute_function_with_args(
+        classify__function_builder(),
+        vec![Token::Num(4).into()],
+    )
+    .unwrap();
+
+    assert_eq!(classify(Token::Num(4)), 0);
+    assert_eq!(expression, 0.into());
+}
@@ -1,4 +1,4 @@
-use algorithmify::Interpreter;
+use algorithmify::{expressions::Integer, Expression, Interpreter};
 use algorithmify_macros::define_function_builder;
 
 #[test]
@@ -15,7 +15,7 @@ pub fn test_vector() {
     let expression = Interpreter::execute_function(vector__function_builder()).unwrap();
 
     assert_eq!(vector(), 6);
-    assert_eq!(expression, 6i64.into());
+    assert!(matches!(expression, Expression::Integer(Integer::Usize(6))));
 }
 
 #[test]
@@ -68,3 +68,19 @@ pub fn test_method_call() {
     assert_eq!(method_call(), 3);
     assert_eq!(expression, 3usize.into());
 }
+
+#[test]
+pub fn test_integer_conversions() {
+    let large = Expression::Integer(Integer::U64(u64::MAX));
+
+    assert_eq!(
+        usize::try_from(large.clone()).unwrap(),
+        usize::try_from(u64::MAX).unwrap()
+    );
+    assert!(i64::try_from(large).is_err());
+
+    let negative = Expression::Integer(Integer::I64(-1));
+
+    assert_eq!(i8::try_from(negative.clone()).unwrap(), -1);
+    assert!(u64::try_from(negative).is_err());
+}
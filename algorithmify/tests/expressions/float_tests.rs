@@ -0,0 +1,77 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_newton_square_root() {
+    #[define_function_builder]
+    fn newton_sqrt(n: f64) -> f64 {
+        let mut x = n;
+
+        while (x * x - n).abs() > 1e-9 {
+            x = (x + n / x) * 0.5;
+        }
+
+        x
+    }
+
+    let expression =
+        Interpreter::execute_function_with_args(newton_sqrt__function_builder(), vec![2.0.into()])
+            .unwrap();
+
+    let result: f64 = expression.try_into().unwrap();
+
+    assert!((newton_sqrt(2.0) - 2f64.sqrt()).abs() < 1e-9);
+    assert_eq!(result, newton_sqrt(2.0));
+}
+
+#[test]
+pub fn test_bisection() {
+    #[define_function_builder]
+    fn bisection(target: f32) -> f32 {
+        let mut low = 0.0f32;
+        let mut high = 1e2_f32;
+
+        while high - low > 0.001f32 {
+            let middle = (low + high) / 2.0f32;
+            if middle * middle * middle < target {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    }
+
+    let expression =
+        Interpreter::execute_function_with_args(bisection__function_builder(), vec![27f32.into()])
+            .unwrap();
+
+    assert!((bisection(27.0) - 3.0).abs() < 0.001);
+    assert_eq!(expression, bisection(27.0).into());
+}
+
+#[test]
+pub fn test_horner() {
+    #[define_function_builder]
+    fn horner(coefficients: Vec<f64>, x: f64) -> f64 {
+        let mut result = 0.0;
+
+        for i in 0..coefficients.len() {
+            result = result * x + coefficients[i];
+        }
+
+        result
+    }
+
+    let coefficients = vec![2.0, 0.5, 1.25];
+
+    let expression = Interpreter::execute_function_with_args(
+        horner__function_builder(),
+        vec![coefficients.clone().into(), 2.0.into()],
+    )
+    .unwrap();
+
+    assert_eq!(horner(coefficients, 2.0), 10.25);
+    assert_eq!(expression, 10.25.into());
+}
@@ -0,0 +1,121 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+pub fn test_printed_output() {
+    #[define_function_builder]
+    fn trace_sum(v: Vec<i32>) -> i32 {
+        let mut sum = 0;
+
+        for (i, x) in v.iter().enumerate() {
+            sum += *x;
+            println!("step {}: sum = {sum}", i);
+        }
+
+        print!("done");
+        eprintln!();
+        eprint!("{:?}", v);
+
+        sum
+    }
+
+    let mut interpreter = Interpreter::new(Default::default());
+    let expression = interpreter
+        .run_with_args(trace_sum__function_builder(), vec![vec![3, -1, 4].into()])
+        .unwrap();
+
+    assert_eq!(trace_sum(vec![3, -1, 4]), 6);
+    assert_eq!(expression, 6.into());
+    assert_eq!(
+        interpreter.output(),
+        "step 0: sum = 3\nstep 1: sum = 2\nstep 2: sum = 6\ndone\n[3, -1, 4]"
+    );
+}
+
+#[define_function_builder]
+fn log_step(step: usize) {
+    println!("step {}", step);
+}
+
+#[test]
+pub fn test_nested_printed_output() {
+    #[define_function_builder]
+    fn log_steps(n: usize) {
+        let log_twice = |step: usize| {
+            log_step(step);
+            println!("again");
+        };
+
+        for i in 0..n {
+            log_twice(i);
+        }
+    }
+
+    #[define_function_builder]
+    fn log_start() {
+        println!("start");
+    }
+
+    log_start();
+    log_steps(2);
+
+    let mut interpreter = Interpreter::new(Default::default());
+    interpreter.run(log_start__function_builder()).unwrap();
+
+    let mut other = Interpreter::new(Default::default());
+    other
+        .run_with_args(log_step__function_builder(), vec![7usize.into()])
+        .unwrap();
+
+    interpreter
+        .run_with_args(log_steps__function_builder(), vec![2usize.into()])
+        .unwrap();
+
+    assert_eq!(
+        interpreter.output(),
+        "start\nstep 0\nagain\nstep 1\nagain\n"
+    );
+    assert_eq!(other.output(), "step 7\n");
+}
+
+#[test]
+pub fn test_format_values() {
+    #[define_function_builder]
+    fn describe(point: Point, values: Vec<(char, Option<f64>)>) -> String {
+        let label = format!("{{{0}, {0}}}", point.x == point.y);
+
+        format!(
+            "{label} {:?} {:?} \"{name}\"\t{}",
+            point,
+            values,
+            values.len(),
+            name = "p"
+        )
+    }
+
+    let point = Point { x: 1, y: 2 };
+    let values = vec![('a', Some(1.0)), ('b', None)];
+
+    let mut interpreter = Interpreter::new(Default::default());
+    let expression = interpreter
+        .run_with_args(
+            describe__function_builder(),
+            vec![point.clone().into(), values.clone().into()],
+        )
+        .unwrap();
+
+    let expected = describe(point, values);
+
+    assert_eq!(
+        expected,
+        "{false, false} Point { x: 1, y: 2 } [('a', Some(1.0)), ('b', None)] \"p\"\t2"
+    );
+    assert_eq!(expression, expected.into());
+    assert_eq!(interpreter.output(), "");
+}
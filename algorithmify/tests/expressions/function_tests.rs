@@ -0,0 +1,250 @@
+use algorithmify::{expressions::Type, Interpreter};
+use algorithmify_macros::{define_function_builder, define_method_builders};
+
+#[define_function_builder]
+fn largest<T: PartialOrd + Copy>(vector: Vec<T>, fallback: T) -> Option<T> {
+    let mut largest = None;
+    let mut i = 0;
+
+    while i < vector.len() {
+        if largest.is_none() || vector[i] > fallback {
+            largest = Some(vector[i]);
+        }
+        i += 1;
+    }
+
+    largest
+}
+
+#[test]
+pub fn test_function_signature() {
+    let function = largest__function_builder();
+
+    assert_eq!(function.name(), "largest");
+    assert_eq!(function.generics(), ["T".to_owned()]);
+    assert_eq!(
+        function.args(),
+        [
+            (
+                "vector".to_owned(),
+                Type::Vector(Box::new(Type::Named("T".to_owned(), vec![])))
+            ),
+            ("fallback".to_owned(), Type::Named("T".to_owned(), vec![])),
+        ]
+    );
+    assert_eq!(
+        function.return_type(),
+        &Type::Option(Box::new(Type::Named("T".to_owned(), vec![])))
+    );
+}
+
+#[test]
+pub fn test_wrong_argument_count() {
+    let error = Interpreter::execute_function_with_args(
+        largest__function_builder(),
+        vec![vec![1, 2].into()],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Function 'largest' expects 2 arguments, got 1"
+    );
+}
+
+#[test]
+pub fn test_wrong_argument_kind() {
+    #[define_function_builder]
+    fn halve(value: u32) -> u32 {
+        value / 2
+    }
+
+    let error =
+        Interpreter::execute_function_with_args(halve__function_builder(), vec![true.into()])
+            .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .starts_with("Argument 'value' of function 'halve' expects U32"));
+
+    assert_eq!(halve(8), 4);
+    assert_eq!(
+        Interpreter::execute_function_with_args(halve__function_builder(), vec![8u32.into()])
+            .unwrap(),
+        4u32.into()
+    );
+}
+
+#[test]
+pub fn test_wrong_argument_width_and_sign() {
+    #[define_function_builder]
+    fn take_usize(value: usize) -> usize {
+        value
+    }
+
+    let execute = |arg: algorithmify::Expression| {
+        Interpreter::execute_function_with_args(take_usize__function_builder(), vec![arg])
+    };
+
+    let error = execute((-1i64).into()).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Argument 'value' of function 'take_usize' expects Usize"));
+
+    assert!(execute(3u32.into()).is_err());
+    assert!(execute((-1).into()).is_err());
+    assert_eq!(execute(3.into()).unwrap(), 3usize.into());
+    assert_eq!(execute(3usize.into()).unwrap(), 3usize.into());
+}
+
+#[test]
+pub fn test_wrong_named_argument() {
+    #[define_function_builder]
+    fn base_of(digits: Digits) -> usize {
+        digits.base
+    }
+
+    let execute = |arg: algorithmify::Expression| {
+        Interpreter::execute_function_with_args(base_of__function_builder(), vec![arg])
+    };
+
+    let error = execute(10.into()).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Argument 'digits' of function 'base_of' expects Named"));
+
+    assert!(execute("10".into()).is_err());
+    assert!(execute(vec![10].into()).is_err());
+    assert_eq!(base_of(Digits { base: 10 }), 10);
+    assert_eq!(execute(Digits { base: 10 }.into()).unwrap(), 10usize.into());
+}
+
+mod utils {
+    use algorithmify_macros::define_function_builder;
+
+    #[define_function_builder]
+    pub fn swap(mut v: Vec<i32>, i: usize, j: usize) -> Vec<i32> {
+        let temp = v[i];
+        v[i] = v[j];
+        v[j] = temp;
+        v
+    }
+
+    pub(crate) mod heap {
+        use algorithmify_macros::define_function_builder;
+
+        #[define_function_builder]
+        pub(crate) fn sift_down(mut v: Vec<i32>, mut i: usize) -> Vec<i32> {
+            loop {
+                let mut largest = i;
+
+                for child in 2 * i + 1..=2 * i + 2 {
+                    if child < v.len() && v[child] > v[largest] {
+                        largest = child;
+                    }
+                }
+
+                if largest == i {
+                    break;
+                }
+
+                v = super::swap(v, i, largest);
+                i = largest;
+            }
+
+            v
+        }
+    }
+}
+
+#[test]
+pub fn test_path_qualified_calls() {
+    #[define_function_builder]
+    fn build_heap(mut v: Vec<i32>) -> Vec<i32> {
+        for i in (0..v.len() / 2).rev() {
+            v = crate::expressions::function_tests::utils::heap::sift_down(v, i);
+        }
+
+        utils::swap(v, 0, 0)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        build_heap__function_builder(),
+        vec![vec![1, 5, 3, 8, 2, 9].into()],
+    )
+    .unwrap();
+
+    assert_eq!(build_heap(vec![1, 5, 3, 8, 2, 9]), vec![9, 8, 3, 5, 2, 1]);
+    assert_eq!(expression, vec![9, 8, 3, 5, 2, 1].into());
+}
+
+#[test]
+pub fn test_std_constructors() {
+    #[define_function_builder]
+    fn collect_words(n: usize) -> (Vec<String>, String) {
+        let mut words = Vec::with_capacity(n);
+        let mut joined = String::new();
+
+        for i in 0..n {
+            words.push(String::from("word"));
+            joined.push_str(&i.to_string());
+        }
+
+        (words, joined)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        collect_words__function_builder(),
+        vec![3usize.into()],
+    )
+    .unwrap();
+
+    let expected = (vec!["word".to_owned(); 3], "012".to_owned());
+
+    assert_eq!(collect_words(3), expected);
+    assert_eq!(expression, expected.into());
+}
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+struct Digits {
+    base: usize,
+}
+
+#[define_method_builders]
+impl Digits {
+    fn count(n: usize, base: usize) -> usize {
+        if n < base {
+            1
+        } else {
+            1 + Self::count(n / base, base)
+        }
+    }
+
+    fn total(&self, values: Vec<usize>) -> usize {
+        let mut total = 0;
+        for value in values.iter() {
+            total += Self::count(*value, self.base);
+        }
+        total
+    }
+}
+
+#[test]
+pub fn test_self_qualified_calls() {
+    #[define_function_builder]
+    fn total_digits(values: Vec<usize>) -> usize {
+        let digits = Digits { base: 10 };
+        digits.total(values)
+    }
+
+    let values = vec![7usize, 42, 1000];
+
+    let expression = Interpreter::execute_function_with_args(
+        total_digits__function_builder(),
+        vec![values.clone().into()],
+    )
+    .unwrap();
+
+    assert_eq!(total_digits(values), 7);
+    assert_eq!(expression, 7usize.into());
+}
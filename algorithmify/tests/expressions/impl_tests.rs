@@ -0,0 +1,185 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::{define_function_builder, define_method_builders};
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+struct MinHeap {
+    data: Vec<i32>,
+}
+
+#[define_method_builders]
+impl MinHeap {
+    fn new() -> Self {
+        Self { data: Vec::new() }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn parent(&self, i: usize) -> usize {
+        (i - 1) / 2
+    }
+
+    fn left(i: usize) -> usize {
+        2 * i + 1
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        let temp = self.data[i];
+        self.data[i] = self.data[j];
+        self.data[j] = temp;
+    }
+
+    fn push(&mut self, value: i32) {
+        self.data.push(value);
+
+        let mut i = self.len() - 1;
+        while i > 0 && self.data[self.parent(i)] > self.data[i] {
+            let parent = self.parent(i);
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn pop(&mut self) -> Option<i32> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let last = self.data.len() - 1;
+        self.swap(0, last);
+        let min = self.data.pop();
+        self.sift_down(0);
+
+        min
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let left = Self::left(i);
+            let mut smallest = i;
+
+            if left < self.len() && self.data[left] < self.data[smallest] {
+                smallest = left;
+            }
+
+            if left + 1 < self.len() && self.data[left + 1] < self.data[smallest] {
+                smallest = left + 1;
+            }
+
+            if smallest == i {
+                break;
+            }
+
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+#[define_method_builders]
+impl DisjointSet {
+    pub fn new(size: usize) -> DisjointSet {
+        let mut parents = Vec::new();
+
+        for i in 0..size {
+            parents.push(i);
+        }
+
+        DisjointSet {
+            parents,
+            sizes: vec![1; size],
+        }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parents[x] != x {
+            let root = self.find(self.parents[x]);
+            // path compression
+            self.parents[x] = root;
+        }
+
+        self.parents[x]
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            let temp = a;
+            a = b;
+            b = temp;
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+
+        true
+    }
+}
+
+#[test]
+pub fn test_heap_sort_with_methods() {
+    #[define_function_builder]
+    fn heap_sort(v: Vec<i32>) -> Vec<i32> {
+        let mut heap = MinHeap::new();
+
+        for x in v.iter() {
+            heap.push(*x);
+        }
+
+        let mut sorted = Vec::new();
+        while let Some(x) = heap.pop() {
+            sorted.push(x);
+        }
+
+        sorted
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        heap_sort__function_builder(),
+        vec![vec![5, -1, 8, 3, 3, 0].into()],
+    )
+    .unwrap();
+
+    assert_eq!(heap_sort(vec![5, -1, 8, 3, 3, 0]), vec![-1, 0, 3, 3, 5, 8]);
+    assert_eq!(expression, vec![-1, 0, 3, 3, 5, 8].into());
+}
+
+#[test]
+pub fn test_mutable_self_is_seen_by_the_caller() {
+    #[define_function_builder]
+    fn connected_components(n: usize, edges: Vec<(usize, usize)>) -> (usize, DisjointSet) {
+        let mut set = DisjointSet::new(n);
+        let mut components = n;
+
+        for (a, b) in edges.iter() {
+            if set.union(*a, *b) {
+                components -= 1;
+            }
+        }
+
+        (components, set)
+    }
+
+    let edges = vec![(0, 1), (2, 3), (1, 0), (4, 2)];
+
+    let expression = Interpreter::execute_function_with_args(
+        connected_components__function_builder(),
+        vec![5usize.into(), edges.clone().into()],
+    )
+    .unwrap();
+
+    assert_eq!(expression, connected_components(5, edges).into());
+}
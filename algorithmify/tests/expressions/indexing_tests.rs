@@ -0,0 +1,162 @@
+use algorithmify::{Expression, Interpreter};
+use algorithmify_macros::define_function_builder;
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+struct Edge {
+    to: usize,
+    weight: i32,
+}
+
+#[test]
+pub fn test_grid_unique_paths() {
+    #[define_function_builder]
+    fn unique_paths(rows: usize, columns: usize) -> usize {
+        let mut dp = vec![vec![0usize; columns]; rows];
+
+        for i in 0..rows {
+            dp[i][0] = 1;
+        }
+
+        for j in 0..columns {
+            dp[0][j] = 1;
+        }
+
+        for i in 1..rows {
+            for j in 1..columns {
+                dp[i][j] = dp[i - 1][j] + dp[i][j - 1];
+            }
+        }
+
+        dp[rows - 1][columns - 1]
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        unique_paths__function_builder(),
+        vec![4usize.into(), 5usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(unique_paths(4, 5), 35);
+    assert_eq!(expression, 35usize.into());
+}
+
+#[test]
+pub fn test_adjacency_matrix() {
+    #[define_function_builder]
+    fn count_paths_of_two(adj: Vec<Vec<bool>>) -> usize {
+        let mut count = 0;
+
+        for u in 0..adj.len() {
+            for v in 0..adj.len() {
+                for k in 0..adj[u].len() {
+                    if adj[u][k] && adj[k][v] {
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    let adj = vec![
+        vec![false, true, true],
+        vec![false, false, true],
+        vec![true, false, false],
+    ];
+
+    let expression = Interpreter::execute_function_with_args(
+        count_paths_of_two__function_builder(),
+        vec![adj.clone().into()],
+    )
+    .unwrap();
+
+    assert_eq!(count_paths_of_two(adj), 5);
+    assert_eq!(expression, 5.into());
+}
+
+#[test]
+pub fn test_nested_place_mutation() {
+    #[define_function_builder]
+    fn build_graph() -> Vec<Vec<Edge>> {
+        let mut graph = vec![vec![]; 3];
+
+        graph[0].push(Edge { to: 1, weight: 4 });
+        graph[0].push(Edge { to: 2, weight: 1 });
+        graph[1].push(Edge { to: 2, weight: 7 });
+
+        graph[0][1].weight = 2;
+        graph[1][0].weight += 3;
+        graph[2] = vec![Edge { to: 0, weight: 5 }];
+
+        graph
+    }
+
+    let expression = Interpreter::execute_function(build_graph__function_builder()).unwrap();
+
+    assert_eq!(
+        Vec::<Vec<Edge>>::try_from(expression).unwrap(),
+        build_graph()
+    );
+}
+
+#[test]
+pub fn test_assignment_evaluation_order() {
+    #[define_function_builder]
+    fn assign_after_push() -> Vec<i32> {
+        let mut v = vec![1, 2, 3];
+        let mut edges = vec![Edge { to: 0, weight: 1 }; 2];
+        let mut i = 0;
+
+        v[i] = {
+            i += 1;
+            4
+        };
+        edges[i].weight = {
+            i -= 1;
+            5
+        };
+        v.push(edges[0].weight);
+        v.push(edges[1].weight);
+
+        v
+    }
+
+    let expression = Interpreter::execute_function(assign_after_push__function_builder()).unwrap();
+
+    assert_eq!(assign_after_push(), vec![1, 4, 3, 5, 1]);
+    assert_eq!(expression, vec![1, 4, 3, 5, 1].into());
+}
+
+#[test]
+pub fn test_indexing_into_values() {
+    #[define_function_builder]
+    fn corner() -> i32 {
+        let pairs = vec![(1, vec![2, 3]), (4, vec![5, 6])];
+        let mut table = vec![vec![vec![0; 2]; 2]; 2];
+
+        table[1][1][1] = pairs[1].1[0] + pairs.last().unwrap().1[1];
+
+        table[1][1][1] - pairs[0].1[1]
+    }
+
+    let expression = Interpreter::execute_function(corner__function_builder()).unwrap();
+
+    assert_eq!(corner(), 8);
+    assert_eq!(expression, Expression::from(8));
+}
+
+#[test]
+pub fn test_index_out_of_bounds() {
+    #[define_function_builder]
+    fn read_past_end(v: Vec<i32>) -> i32 {
+        v[v.len()]
+    }
+
+    let result = Interpreter::execute_function_with_args(
+        read_past_end__function_builder(),
+        vec![vec![1, 2].into()],
+    );
+
+    assert!(result.is_err());
+}
@@ -1,4 +1,4 @@
-use algorithmify::Interpreter;
+use algorithmify::{expressions::Integer, Expression, Interpreter};
 use algorithmify_macros::define_function_builder;
 
 #[test]
@@ -17,7 +17,10 @@ pub fn test_for_loop() {
     let expression = Interpreter::execute_function(for_loop__function_builder()).unwrap();
 
     assert_eq!(for_loop(), 55);
-    assert_eq!(expression, 55i64.into());
+    assert!(matches!(
+        expression,
+        Expression::Integer(Integer::Usize(55))
+    ));
 }
 
 #[test]
@@ -38,7 +41,10 @@ pub fn test_for_loop_variable_bounds() {
     let expression = Interpreter::execute_function(for_loop__function_builder()).unwrap();
 
     assert_eq!(for_loop(), 55);
-    assert_eq!(expression, 55i64.into());
+    assert!(matches!(
+        expression,
+        Expression::Integer(Integer::Usize(55))
+    ));
 }
 
 #[test]
@@ -79,3 +85,326 @@ pub fn test_while_loop() {
     assert_eq!(while_loop(), 10);
     assert_eq!(expression, 10.into());
 }
+
+#[test]
+pub fn test_break() {
+    #[define_function_builder]
+    fn break_loop() -> usize {
+        let mut acc = 0;
+
+        while acc < 100 {
+            if acc == 5 {
+                break;
+            }
+
+            acc = acc + 1;
+        }
+
+        acc
+    }
+
+    let expression = Interpreter::execute_function(break_loop__function_builder()).unwrap();
+
+    assert_eq!(break_loop(), 5);
+    assert_eq!(expression, 5.into());
+}
+
+#[test]
+pub fn test_continue() {
+    #[define_function_builder]
+    fn continue_loop() -> usize {
+        let mut acc = 0;
+
+        for i in 0..10 {
+            {
+                if i & 1 == 1 {
+                    continue;
+                }
+            }
+
+            acc = acc + i;
+        }
+
+        acc
+    }
+
+    let expression = Interpreter::execute_function(continue_loop__function_builder()).unwrap();
+
+    assert_eq!(continue_loop(), 20);
+    assert!(matches!(
+        expression,
+        Expression::Integer(Integer::Usize(20))
+    ));
+}
+
+#[test]
+pub fn test_labeled_break() {
+    #[define_function_builder]
+    fn labeled_break() -> usize {
+        let mut count = 0;
+
+        'outer: for i in 0..10 {
+            for j in 0..10 {
+                if i * j == 6 {
+                    break 'outer;
+                }
+
+                count = count + 1;
+            }
+        }
+
+        count
+    }
+
+    let expression = Interpreter::execute_function(labeled_break__function_builder()).unwrap();
+
+    assert_eq!(labeled_break(), 16);
+    assert_eq!(expression, 16.into());
+}
+
+#[test]
+pub fn test_labeled_continue() {
+    #[define_function_builder]
+    fn labeled_continue() -> usize {
+        let mut count = 0;
+
+        'outer: for i in 0..5 {
+            let mut j = 0;
+
+            while j < 5 {
+                if j > i {
+                    continue 'outer;
+                }
+
+                count = count + 1;
+                j = j + 1;
+            }
+        }
+
+        count
+    }
+
+    let expression = Interpreter::execute_function(labeled_continue__function_builder()).unwrap();
+
+    assert_eq!(labeled_continue(), 15);
+    assert_eq!(expression, 15.into());
+}
+
+#[test]
+pub fn test_infinite_loop() {
+    #[define_function_builder]
+    fn gcd(a: u64, b: u64) -> u64 {
+        let mut a = a;
+        let mut b = b;
+
+        loop {
+            if b == 0 {
+                break a;
+            }
+
+            let remainder = a - (a / b) * b;
+            a = b;
+            b = remainder;
+        }
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        gcd__function_builder(),
+        vec![48.into(), 18.into()],
+    )
+    .unwrap();
+
+    assert_eq!(gcd(48, 18), 6);
+    assert_eq!(expression, 6.into());
+}
+
+#[test]
+pub fn test_infinite_loop_break_value() {
+    #[define_function_builder]
+    fn loop_value() -> i32 {
+        let mut counter = 0;
+
+        let result = loop {
+            counter = counter + 1;
+
+            if counter == 10 {
+                break counter * 2;
+            }
+        };
+
+        result
+    }
+
+    let expression = Interpreter::execute_function(loop_value__function_builder()).unwrap();
+
+    assert_eq!(loop_value(), 20);
+    assert_eq!(expression, 20.into());
+}
+
+#[define_function_builder]
+fn infinite_loop_pre_condition(counter: usize) -> bool {
+    counter == 0
+}
+
+#[define_function_builder]
+fn infinite_loop_maintenance_condition(counter: usize) -> bool {
+    counter <= 5
+}
+
+#[define_function_builder]
+fn infinite_loop_post_condition(counter: usize) -> bool {
+    counter == 5
+}
+
+#[define_function_builder {
+    main: {
+        pre_condition: infinite_loop_pre_condition,
+        maintenance_condition: infinite_loop_maintenance_condition,
+        post_condition: infinite_loop_post_condition,
+    }
+}]
+fn infinite_loop_with_contract() -> usize {
+    let mut counter = 0;
+
+    'main: loop {
+        counter = counter + 1;
+
+        if counter == 5 {
+            break 'main counter;
+        }
+    }
+}
+
+#[test]
+pub fn test_infinite_loop_contract() {
+    let expression =
+        Interpreter::execute_function(infinite_loop_with_contract__function_builder()).unwrap();
+
+    assert_eq!(infinite_loop_with_contract(), 5);
+    assert_eq!(expression, 5.into());
+}
+
+#[test]
+pub fn test_for_loop_over_elements() {
+    #[define_function_builder]
+    fn weighted_sum(v: Vec<i32>) -> i32 {
+        let mut sum = 0;
+
+        for x in v.iter() {
+            sum += *x;
+        }
+
+        for (i, x) in v.iter().enumerate() {
+            sum += i as i32 * x;
+        }
+
+        sum
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        weighted_sum__function_builder(),
+        vec![vec![3, -1, 4, 1].into()],
+    )
+    .unwrap();
+
+    assert_eq!(weighted_sum(vec![3, -1, 4, 1]), 17);
+    assert_eq!(expression, 17.into());
+}
+
+#[test]
+pub fn test_for_loop_rev_step_by_and_inclusive_ranges() {
+    #[define_function_builder]
+    fn visit_order(n: usize) -> Vec<usize> {
+        let mut order = vec![];
+
+        for i in (0..n).rev() {
+            order.push(i);
+        }
+
+        for i in (0..n).step_by(2) {
+            order.push(i);
+        }
+
+        for i in 1..=n {
+            order.push(i * 10);
+        }
+
+        for i in (1..=n).rev().step_by(3) {
+            order.push(i * 100);
+        }
+
+        order
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        visit_order__function_builder(),
+        vec![4usize.into()],
+    )
+    .unwrap();
+
+    let expected = vec![3, 2, 1, 0, 0, 2, 10, 20, 30, 40, 400, 100];
+
+    assert_eq!(visit_order(4), expected);
+    assert_eq!(expression, expected.into());
+}
+
+#[test]
+pub fn test_for_loop_signed_bounds() {
+    #[define_function_builder]
+    fn signed_sum() -> i32 {
+        let mut sum = 0;
+
+        for i in -3..=2 {
+            sum += i;
+        }
+
+        sum
+    }
+
+    let expression = Interpreter::execute_function(signed_sum__function_builder()).unwrap();
+
+    assert_eq!(signed_sum(), -3);
+    assert_eq!(expression, Expression::Integer(Integer::I32(-3)));
+}
+
+#[define_function_builder]
+fn max_maintenance_condition(i: usize, best: i32, v: Vec<i32>) -> bool {
+    let mut valid = true;
+
+    for j in 0..=i {
+        if v[j] > best {
+            valid = false;
+        }
+    }
+
+    valid
+}
+
+#[define_function_builder {
+    main: {
+        maintenance_condition: max_maintenance_condition,
+    }
+}]
+fn max_with_contract(v: Vec<i32>) -> i32 {
+    let mut best = v[0];
+
+    'main: for (i, x) in v.iter().enumerate() {
+        if *x > best {
+            best = v[i];
+        }
+    }
+
+    best
+}
+
+#[test]
+pub fn test_iterator_for_loop_contract() {
+    let expression = Interpreter::execute_function_with_args(
+        max_with_contract__function_builder(),
+        vec![vec![2, 7, 1, 8, 2].into()],
+    )
+    .unwrap();
+
+    assert_eq!(max_with_contract(vec![2, 7, 1, 8, 2]), 8);
+    assert_eq!(expression, 8.into());
+}
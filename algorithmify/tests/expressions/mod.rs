@@ -1,5 +1,22 @@
+mod assertion_tests;
 mod block_tests;
+mod cast_tests;
+mod closure_tests;
 mod condition_tests;
+mod control_flow_tests;
+mod enum_tests;
 mod expression_test;
+mod float_tests;
+mod format_tests;
+mod function_tests;
+mod impl_tests;
+mod indexing_tests;
 mod loop_tests;
+mod mutable_reference_tests;
 mod operations_tests;
+mod option_tests;
+mod slice_tests;
+mod string_tests;
+mod struct_tests;
+mod tuple_tests;
+mod type_tests;
@@ -0,0 +1,123 @@
+use algorithmify::{Expression, Interpreter};
+use algorithmify_macros::define_function_builder;
+
+#[define_function_builder]
+fn swap(v: &mut [i32], i: usize, j: usize) {
+    let temp = v[i];
+    v[i] = v[j];
+    v[j] = temp;
+}
+
+#[define_function_builder]
+fn insertion_sort(v: &mut [i32]) {
+    for i in 1..v.len() {
+        let mut j = i;
+
+        while j > 0 && v[j - 1] > v[j] {
+            swap(v, j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[define_function_builder]
+fn increment(x: &mut usize) {
+    *x += 1;
+}
+
+#[allow(unused_variables)]
+#[define_function_builder]
+fn do_nothing(v: &mut [i32]) {}
+
+#[test]
+pub fn test_in_place_procedure() {
+    let mut v = vec![4, -2, 9, 0, 4];
+
+    let expression = Interpreter::execute_function_with_args(
+        insertion_sort__function_builder(),
+        vec![v.clone().into()],
+    )
+    .unwrap();
+
+    insertion_sort(&mut v);
+
+    assert_eq!(v, vec![-2, 0, 4, 4, 9]);
+    assert_eq!(expression, v.into());
+}
+
+#[test]
+pub fn test_writes_propagate_to_caller_places() {
+    #[define_function_builder]
+    fn count_and_sort(mut v: Vec<i32>) -> (Vec<usize>, Vec<i32>) {
+        let mut counts = vec![0; 3];
+
+        for x in v.iter() {
+            increment(&mut counts[(*x % 3) as usize]);
+        }
+
+        insertion_sort(&mut v);
+        do_nothing(&mut v);
+
+        (counts, v)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        count_and_sort__function_builder(),
+        vec![vec![5, 3, 1, 2, 6].into()],
+    )
+    .unwrap();
+
+    let expected = (vec![2, 1, 2], vec![1, 2, 3, 5, 6]);
+
+    assert_eq!(count_and_sort(vec![5, 3, 1, 2, 6]), expected);
+    assert_eq!(expression, expected.into());
+}
+
+#[test]
+pub fn test_final_state_of_several_mutable_args() {
+    #[define_function_builder]
+    fn reset(x: &mut usize, v: &mut Vec<i32>) {
+        *x = 0;
+        *v = vec![1];
+        v.push(2);
+        v.swap(0, 1);
+    }
+
+    let (mut x, mut v) = (7usize, vec![3, 2]);
+
+    let expression = Interpreter::execute_function_with_args(
+        reset__function_builder(),
+        vec![x.into(), v.clone().into()],
+    )
+    .unwrap();
+
+    reset(&mut x, &mut v);
+
+    assert_eq!(expression, Expression::Tuple(vec![x.into(), v.into()]));
+}
+
+#[test]
+pub fn test_mutable_places_resolved_once() {
+    #[define_function_builder]
+    fn next_index(counter: &mut usize) -> usize {
+        *counter += 1;
+        *counter - 1
+    }
+
+    #[define_function_builder]
+    fn bump_next(mut counts: Vec<usize>) -> (Vec<usize>, usize) {
+        let mut counter = 0;
+        increment(&mut counts[next_index(&mut counter)]);
+        increment(&mut counts[next_index(&mut counter)]);
+        (counts, counter)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        bump_next__function_builder(),
+        vec![vec![0usize; 3].into()],
+    )
+    .unwrap();
+
+    assert_eq!(bump_next(vec![0; 3]), (vec![1, 1, 0], 2));
+    assert_eq!(expression, (vec![1usize, 1, 0], 2usize).into());
+}
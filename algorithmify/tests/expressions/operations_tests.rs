@@ -1,4 +1,7 @@
-use algorithmify::{Expression, Interpreter};
+use algorithmify::{
+    expressions::{Reference, Statement, Type, UnaryOperation},
+    Expression, Interpreter,
+};
 use algorithmify_macros::define_function_builder;
 
 #[test]
@@ -126,3 +129,300 @@ fn boolean_logic_test() {
     assert_eq!(boolean_logic_false(), false);
     assert_eq!(expression, Expression::Bool(false));
 }
+
+#[test]
+fn negation_test() {
+    #[define_function_builder]
+    fn negation() -> i32 {
+        let a = 5;
+        let b = -a * 2;
+        let c = -1;
+        b - -c + -(a - 8)
+    }
+
+    let expression = Interpreter::execute_function(negation__function_builder()).unwrap();
+
+    assert_eq!(negation(), -8);
+    assert_eq!(expression, (-8).into());
+}
+
+#[test]
+fn float_negation_test() {
+    #[define_function_builder]
+    fn float_negation(x: f64) -> f64 {
+        -x.abs() * 2.0
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        float_negation__function_builder(),
+        vec![(-1.5).into()],
+    )
+    .unwrap();
+
+    assert_eq!(float_negation(-1.5), -3.0);
+    assert_eq!(expression, (-3.0).into());
+}
+
+#[test]
+fn unsigned_negation_test() {
+    let function = algorithmify::Function::new(
+        "unsigned_negation".to_owned(),
+        vec![],
+        vec![("a".to_owned(), Type::U32)],
+        Type::U32,
+        vec![Statement::Expression(Expression::UnaryOperation(Box::new(
+            UnaryOperation::Neg(Expression::Reference(Reference::Variable("a".to_owned()))),
+        )))],
+        Default::default(),
+    );
+
+    assert!(Interpreter::execute_function_with_args(function, vec![1u32.into()]).is_err());
+}
+
+#[test]
+fn logical_not_test() {
+    #[define_function_builder]
+    fn find(vector: Vec<i32>, value: i32) -> bool {
+        let mut found = false;
+        let mut i = 0;
+
+        while !found && i < vector.len() {
+            found = !(vector[i] != value);
+            i = i + 1;
+        }
+
+        !!found
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        find__function_builder(),
+        vec![vec![3, 1, 4].into(), 1.into()],
+    )
+    .unwrap();
+
+    assert_eq!(find(vec![3, 1, 4], 1), true);
+    assert_eq!(expression, true.into());
+}
+
+#[test]
+fn bitwise_not_test() {
+    #[define_function_builder]
+    fn clear_lowest_bits(mask: u8) -> u8 {
+        mask & !3
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        clear_lowest_bits__function_builder(),
+        vec![255u8.into()],
+    )
+    .unwrap();
+
+    assert_eq!(clear_lowest_bits(255), 252);
+    assert_eq!(expression, 252u8.into());
+}
+
+#[test]
+fn remainder_test() {
+    #[define_function_builder]
+    fn modular_power(base: u64, exponent: u64, modulus: u64) -> u64 {
+        let mut result = 1;
+        let mut base = base % modulus;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base % modulus;
+            }
+            base = base * base % modulus;
+            exponent = exponent >> 1;
+        }
+
+        result
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        modular_power__function_builder(),
+        vec![3u64.into(), 200u64.into(), 1_000_000_007u64.into()],
+    )
+    .unwrap();
+
+    assert_eq!(expression, modular_power(3, 200, 1_000_000_007).into());
+}
+
+#[test]
+fn shift_and_xor_test() {
+    #[define_function_builder]
+    fn lowest_bit_and_xor(a: usize, b: usize) -> (usize, usize, usize) {
+        let lowest = a & (!a + 1);
+        let shifted = 1 << 4 >> 2;
+        (lowest, a ^ b, shifted)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        lowest_bit_and_xor__function_builder(),
+        vec![12usize.into(), 10usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(lowest_bit_and_xor(12, 10), (4, 6, 4));
+    assert_eq!(expression, (4usize, 6usize, 4usize).into());
+}
+
+#[test]
+fn mixed_operator_precedence_test() {
+    #[allow(clippy::precedence)]
+    #[define_function_builder]
+    fn mixed_operators(x: i32, y: i32, n: i32) -> Vec<i32> {
+        let a = 1;
+        let b = 3;
+        let c = 2;
+        let flag = x & 1 == 1 && y * 2 - 1 > 8 >> 1;
+
+        vec![
+            x + y >> 1,
+            1 << n - 1,
+            a ^ b & c,
+            a | b ^ c & x % 4,
+            flag as i32,
+        ]
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        mixed_operators__function_builder(),
+        vec![3.into(), 5.into(), 3.into()],
+    )
+    .unwrap();
+
+    assert_eq!(mixed_operators(3, 5, 3), vec![4, 4, 3, 1, 1]);
+    assert_eq!(expression, vec![4, 4, 3, 1, 1].into());
+}
+
+#[test]
+fn logical_operator_precedence_test() {
+    #[define_function_builder]
+    fn logical_operators(a: bool, b: bool, c: bool) -> Vec<bool> {
+        vec![a || b && c, c && b || a, !a || b && !c]
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        logical_operators__function_builder(),
+        vec![true.into(), false.into(), false.into()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        logical_operators(true, false, false),
+        vec![true, true, false]
+    );
+    assert_eq!(expression, vec![true, true, false].into());
+}
+
+#[test]
+fn unspaced_unary_operand_test() {
+    #[rustfmt::skip]
+    #[define_function_builder]
+    fn flip_sign(a: i32, b: i32) -> i32 {
+        a*-2 + b/-b
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        flip_sign__function_builder(),
+        vec![4.into(), 2.into()],
+    )
+    .unwrap();
+
+    assert_eq!(flip_sign(4, 2), -9);
+    assert_eq!(expression, (-9).into());
+}
+
+#[test]
+fn invalid_remainder_and_shift_test() {
+    #[define_function_builder]
+    fn remainder(a: u32, b: u32) -> u32 {
+        a % b
+    }
+
+    #[define_function_builder]
+    fn shift(a: u32, b: i32) -> u32 {
+        a << b
+    }
+
+    let execute_remainder = |b: u32| {
+        Interpreter::execute_function_with_args(
+            remainder__function_builder(),
+            vec![7u32.into(), b.into()],
+        )
+    };
+    let execute_shift = |b: i32| {
+        Interpreter::execute_function_with_args(
+            shift__function_builder(),
+            vec![1u32.into(), b.into()],
+        )
+    };
+
+    assert_eq!(execute_remainder(4).unwrap(), remainder(7, 4).into());
+    assert!(execute_remainder(0).is_err());
+    assert_eq!(execute_shift(31).unwrap(), shift(1, 31).into());
+    assert!(execute_shift(32).is_err());
+    assert!(execute_shift(-1).is_err());
+}
+
+#[test]
+fn compound_assignment_test() {
+    #[define_function_builder]
+    fn compound_assignment(n: i32) -> (i32, i32, i32) {
+        let mut acc = 0;
+        for i in 0..n {
+            acc += i;
+        }
+        acc *= 2;
+        acc -= 1;
+        acc /= 3;
+
+        let mut rest = 100;
+        rest %= 7;
+        rest <<= 2;
+        rest >>= 1;
+        rest ^= 1;
+
+        let mut mask = 0;
+        mask |= 6;
+        mask &= 3;
+
+        (acc, rest, mask)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        compound_assignment__function_builder(),
+        vec![5.into()],
+    )
+    .unwrap();
+
+    assert_eq!(compound_assignment(5), (6, 5, 2));
+    assert_eq!(expression, (6, 5, 2).into());
+}
+
+#[test]
+fn indexed_compound_assignment_test() {
+    #[define_function_builder]
+    fn counting(vector: Vec<usize>) -> Vec<usize> {
+        let mut counts = vec![0; 4];
+        let mut i = 0;
+
+        while i < vector.len() {
+            counts[vector[i]] += 1;
+            i += 1;
+        }
+
+        counts
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        counting__function_builder(),
+        vec![vec![1usize, 3, 1, 0].into()],
+    )
+    .unwrap();
+
+    assert_eq!(counting(vec![1, 3, 1, 0]), vec![1, 2, 0, 1]);
+    assert_eq!(expression, vec![1, 2, 0, 1].into());
+}
@@ -0,0 +1,141 @@
+use algorithmify::{Expression, Interpreter};
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_while_let() {
+    #[define_function_builder]
+    fn while_let() -> usize {
+        let mut stack = vec![1, 2, 3];
+        let mut acc = 0;
+
+        while let Some(value) = stack.pop() {
+            if value == 3 {
+                stack.push(10);
+            }
+
+            acc = acc + value;
+        }
+
+        acc
+    }
+
+    let expression = Interpreter::execute_function(while_let__function_builder()).unwrap();
+
+    assert_eq!(while_let(), 16);
+    assert_eq!(expression, 16.into());
+}
+
+#[test]
+pub fn test_if_let() {
+    #[define_function_builder]
+    fn if_let(vector: Vec<usize>) -> usize {
+        if let Some(last) = vector.last().copied() {
+            last
+        } else if let Some(first) = vector.first().copied() {
+            first * 10
+        } else {
+            100
+        }
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        if_let__function_builder(),
+        vec![vec![4usize, 5].into()],
+    )
+    .unwrap();
+    let empty_expression = Interpreter::execute_function_with_args(
+        if_let__function_builder(),
+        vec![Vec::<usize>::new().into()],
+    )
+    .unwrap();
+
+    assert_eq!(if_let(vec![4, 5]), 5);
+    assert_eq!(expression, 5usize.into());
+    assert_eq!(if_let(vec![]), 100);
+    assert_eq!(empty_expression, 100.into());
+}
+
+#[test]
+pub fn test_try_option() {
+    #[define_function_builder]
+    fn checked_difference(a: u32, b: u32, c: u32) -> Option<u32> {
+        let difference = a.checked_sub(b)?.checked_sub(c)?;
+        Some(difference * 2)
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        checked_difference__function_builder(),
+        vec![10u32.into(), 3u32.into(), 2u32.into()],
+    )
+    .unwrap();
+    let none_expression = Interpreter::execute_function_with_args(
+        checked_difference__function_builder(),
+        vec![10u32.into(), 3u32.into(), 8u32.into()],
+    )
+    .unwrap();
+
+    assert_eq!(checked_difference(10, 3, 2), Some(10));
+    assert_eq!(expression, Some(10u32).into());
+    assert_eq!(checked_difference(10, 3, 8), None);
+    assert_eq!(none_expression, Expression::Optional(None));
+    assert_eq!(Option::<u32>::try_from(none_expression).unwrap(), None);
+}
+
+#[test]
+pub fn test_try_result() {
+    #[define_function_builder]
+    fn safe_division(a: i32, b: i32) -> Result<i32, i32> {
+        if b == 0 {
+            return Err(a);
+        }
+
+        Ok(a / b)
+    }
+
+    #[define_function_builder]
+    fn divide_twice(a: i32, b: i32) -> Result<i32, i32> {
+        let result = safe_division(a, b)?;
+        let result = safe_division(result, b)?;
+
+        match safe_division(result, 1) {
+            Ok(value) => Ok(value + 1),
+            Err(error) => Err(error),
+        }
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        divide_twice__function_builder(),
+        vec![20.into(), 2.into()],
+    )
+    .unwrap();
+    let err_expression = Interpreter::execute_function_with_args(
+        divide_twice__function_builder(),
+        vec![20.into(), 0.into()],
+    )
+    .unwrap();
+
+    assert_eq!(divide_twice(20, 2), Ok(6));
+    assert_eq!(expression, Ok::<i32, i32>(6).into());
+    assert_eq!(divide_twice(20, 0), Err(20));
+    assert_eq!(
+        Result::<i32, i32>::try_from(err_expression).unwrap(),
+        Err(20)
+    );
+}
+
+#[test]
+pub fn test_receiver_evaluated_before_arguments() {
+    #[define_function_builder]
+    fn pop_plus_length(mut v: Vec<i32>) -> i32 {
+        v.pop().unwrap().checked_add(v.len() as i32).unwrap()
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        pop_plus_length__function_builder(),
+        vec![vec![1, 2, 3].into()],
+    )
+    .unwrap();
+
+    assert_eq!(pop_plus_length(vec![1, 2, 3]), 5);
+    assert_eq!(expression, 5.into());
+}
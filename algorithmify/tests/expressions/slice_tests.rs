@@ -0,0 +1,185 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[define_function_builder]
+fn merge_sort(v: &[i32]) -> Vec<i32> {
+    if v.len() <= 1 {
+        return v.to_vec();
+    }
+
+    let (left, right) = v.split_at(v.len() / 2);
+    let left = merge_sort(left);
+    let right = merge_sort(right);
+
+    let mut merged = Vec::with_capacity(v.len());
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < left.len() || j < right.len() {
+        if j == right.len() || (i < left.len() && left[i] <= right[j]) {
+            merged.push(left[i]);
+            i += 1;
+        } else {
+            merged.push(right[j]);
+            j += 1;
+        }
+    }
+
+    merged
+}
+
+#[define_function_builder]
+fn contains(v: &[i32], target: i32) -> bool {
+    if v.is_empty() {
+        return false;
+    }
+
+    let middle = v.len() / 2;
+
+    if v[middle] == target {
+        true
+    } else if v[middle] < target {
+        contains(&v[middle + 1..], target)
+    } else {
+        contains(&v[..middle], target)
+    }
+}
+
+#[define_function_builder]
+fn reverse(v: &mut [i32]) {
+    let mut i = 0;
+
+    while i < v.len() / 2 {
+        let j = v.len() - 1 - i;
+        v.swap(i, j);
+        i += 1;
+    }
+}
+
+#[test]
+pub fn test_read_only_slices() {
+    #[define_function_builder]
+    fn sort_and_search(v: Vec<i32>, target: i32) -> (Vec<i32>, bool, bool) {
+        let sorted = merge_sort(&v);
+        let found = contains(&sorted, target);
+        let found_in_tail = contains(&sorted[2..=4], target);
+
+        (sorted, found, found_in_tail)
+    }
+
+    let v = vec![7, -3, 5, 0, 12, 5, 1];
+
+    let expression = Interpreter::execute_function_with_args(
+        sort_and_search__function_builder(),
+        vec![v.clone().into(), 0.into()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        sort_and_search(v.clone(), 0),
+        (vec![-3, 0, 1, 5, 5, 7, 12], true, false)
+    );
+    assert_eq!(expression, sort_and_search(v, 0).into());
+}
+
+#[test]
+pub fn test_mutable_slices() {
+    #[define_function_builder]
+    fn rotate_left(mut v: Vec<i32>, k: usize) -> Vec<i32> {
+        reverse(&mut v[..k]);
+        reverse(&mut v[k..]);
+        reverse(&mut v);
+
+        v[1..3].swap(0, 1);
+
+        v
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        rotate_left__function_builder(),
+        vec![vec![1, 2, 3, 4, 5, 6].into(), 2usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        rotate_left(vec![1, 2, 3, 4, 5, 6], 2),
+        vec![3, 5, 4, 6, 1, 2]
+    );
+    assert_eq!(expression, vec![3, 5, 4, 6, 1, 2].into());
+}
+
+#[test]
+pub fn test_slice_bounds_checks() {
+    #[define_function_builder]
+    fn second_of_window(v: Vec<i32>, start: usize, index: usize) -> i32 {
+        let window = &v[start..start + 2];
+        window[index]
+    }
+
+    #[define_function_builder]
+    fn window_element(v: Vec<i32>, start: usize, index: usize) -> i32 {
+        v[start..start + 2][index]
+    }
+
+    let v = vec![10, 20, 30, 40];
+
+    assert_eq!(
+        Interpreter::execute_function_with_args(
+            second_of_window__function_builder(),
+            vec![v.clone().into(), 1usize.into(), 1usize.into()],
+        )
+        .unwrap(),
+        30.into()
+    );
+
+    let error = Interpreter::execute_function_with_args(
+        second_of_window__function_builder(),
+        vec![v.clone().into(), 3usize.into(), 0usize.into()],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Range 3..5 out of bounds of a slice of length 4"
+    );
+
+    let error = Interpreter::execute_function_with_args(
+        window_element__function_builder(),
+        vec![v.into(), 1usize.into(), 2usize.into()],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Index 2 out of bounds of a slice of length 2"
+    );
+}
+
+#[test]
+pub fn test_mutable_slice_bindings() {
+    #[define_function_builder]
+    fn write_through_views(mut v: Vec<i32>) -> Vec<i32> {
+        let s = &mut v[1..3];
+        s[0] = 9;
+        s.swap(0, 1);
+        reverse(s);
+
+        let first = &mut v[0];
+        *first += 10;
+
+        let tail = &mut v[2..];
+        let last = tail.len() - 1;
+        tail[last] = tail[0] * 2;
+
+        v
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        write_through_views__function_builder(),
+        vec![vec![1, 2, 3, 4].into()],
+    )
+    .unwrap();
+
+    assert_eq!(write_through_views(vec![1, 2, 3, 4]), vec![11, 9, 3, 6]);
+    assert_eq!(expression, vec![11, 9, 3, 6].into());
+}
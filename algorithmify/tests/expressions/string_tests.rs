@@ -0,0 +1,136 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_palindrome() {
+    #[define_function_builder]
+    fn is_palindrome(text: String) -> bool {
+        let characters = text.chars().collect::<Vec<_>>();
+        let mut i = 0;
+        let mut j = characters.len();
+
+        while i + 1 < j {
+            if characters[i] != characters[j - 1] {
+                return false;
+            }
+            i = i + 1;
+            j = j - 1;
+        }
+
+        true
+    }
+
+    for (text, expected) in [("racecar", true), ("abca", false), ("", true)] {
+        let expression = Interpreter::execute_function_with_args(
+            is_palindrome__function_builder(),
+            vec![text.into()],
+        )
+        .unwrap();
+
+        assert_eq!(is_palindrome(text.to_owned()), expected);
+        assert_eq!(expression, expected.into());
+    }
+}
+
+#[test]
+pub fn test_string_building() {
+    #[define_function_builder]
+    fn run_length_encode(text: String) -> String {
+        let bytes = text.as_bytes();
+        let mut encoded = "".to_owned();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let mut count = 1;
+            while i + count < bytes.len() && bytes[i + count] == bytes[i] {
+                count = count + 1;
+            }
+
+            if bytes[i] == b'a' {
+                encoded.push_str("a:");
+            } else {
+                encoded.push('*');
+            }
+            encoded.push_str(&count.to_string());
+
+            i = i + count;
+        }
+
+        encoded
+    }
+
+    let expression = Interpreter::execute_function_with_args(
+        run_length_encode__function_builder(),
+        vec!["aaabb".into()],
+    )
+    .unwrap();
+
+    assert_eq!(run_length_encode("aaabb".to_owned()), "a:3*2");
+    assert_eq!(expression, "a:3*2".into());
+}
+
+#[test]
+pub fn test_string_search() {
+    #[define_function_builder]
+    fn count_matches(words: Vec<String>, prefix: String, letter: char) -> usize {
+        let mut count = 0;
+
+        for word in words.iter() {
+            if word.starts_with(&prefix) && word.contains(letter) {
+                count += 1;
+            } else if word.starts_with('#') || word.contains("--") {
+                count += 10;
+            }
+        }
+
+        count
+    }
+
+    let words = vec![
+        "table".to_owned(),
+        "tab".to_owned(),
+        "tablet".to_owned(),
+        "#tag".to_owned(),
+        "a--b".to_owned(),
+        "cable".to_owned(),
+    ];
+
+    let expression = Interpreter::execute_function_with_args(
+        count_matches__function_builder(),
+        vec![words.clone().into(), "tab".into(), 'l'.into()],
+    )
+    .unwrap();
+
+    assert_eq!(count_matches(words, "tab".to_owned(), 'l'), 22);
+    assert_eq!(expression, 22usize.into());
+}
+
+#[test]
+pub fn test_short_circuit_guards() {
+    #[define_function_builder]
+    fn first_space(text: String) -> usize {
+        let bytes = text.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() && bytes[i] != b' ' {
+            i += 1;
+        }
+
+        if i == bytes.len() || bytes[i] == b' ' {
+            i
+        } else {
+            0
+        }
+    }
+
+    for (text, expected) in [("ab cd", 2), ("abcd", 4), ("", 0)] {
+        let expression = Interpreter::execute_function_with_args(
+            first_space__function_builder(),
+            vec![text.into()],
+        )
+        .unwrap();
+
+        assert_eq!(first_space(text.to_owned()), expected);
+        assert_eq!(expression, expected.into());
+    }
+}
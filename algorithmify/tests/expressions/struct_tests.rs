@@ -0,0 +1,95 @@
+use algorithmify::{Expression, Interpreter};
+use algorithmify_macros::define_function_builder;
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+struct Interval {
+    start: i32,
+    end: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, algorithmify_macros::Expression)]
+pub struct Stack {
+    pub items: Vec<usize>,
+    pub count: usize,
+}
+
+#[test]
+pub fn test_struct_literal_and_field_access() {
+    #[define_function_builder]
+    fn interval_length() -> i32 {
+        let interval = Interval { start: 3, end: 10 };
+        interval.end - interval.start
+    }
+
+    let expression = Interpreter::execute_function(interval_length__function_builder()).unwrap();
+
+    assert_eq!(interval_length(), 7);
+    assert_eq!(expression, 7.into());
+}
+
+#[test]
+pub fn test_struct_field_assignment() {
+    #[define_function_builder]
+    fn merge(a: Interval, b: Interval) -> Interval {
+        let mut merged = a.clone();
+
+        if b.start < merged.start {
+            merged.start = b.start;
+        }
+
+        if b.end > merged.end {
+            merged.end = b.end;
+        }
+
+        merged
+    }
+
+    let a = Interval { start: 4, end: 8 };
+    let b = Interval { start: 1, end: 6 };
+
+    let expression = Interpreter::execute_function_with_args(
+        merge__function_builder(),
+        vec![a.clone().into(), b.clone().into()],
+    )
+    .unwrap();
+
+    assert_eq!(merge(a, b), Interval { start: 1, end: 8 });
+    assert_eq!(
+        Interval::try_from(expression).unwrap(),
+        Interval { start: 1, end: 8 }
+    );
+}
+
+#[test]
+pub fn test_struct_field_method_call() {
+    #[define_function_builder]
+    fn fill(count: usize) -> Stack {
+        let items = vec![];
+        let mut stack = Stack { items, count: 0 };
+
+        while stack.count < count {
+            stack.items.push(stack.count);
+            stack.count = stack.count + 1;
+        }
+
+        stack
+    }
+
+    let expression =
+        Interpreter::execute_function_with_args(fill__function_builder(), vec![3usize.into()])
+            .unwrap();
+
+    let expected = Stack {
+        items: vec![0, 1, 2],
+        count: 3,
+    };
+
+    assert_eq!(fill(3), expected);
+    assert_eq!(expression, expected.into());
+}
+
+#[test]
+pub fn test_struct_conversion_mismatch() {
+    let expression: Expression = (1, 2).into();
+    assert!(Interval::try_from(expression).is_err());
+}
@@ -0,0 +1,96 @@
+use algorithmify::Interpreter;
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_tuple_field_access() {
+    #[define_function_builder]
+    fn tuple_field_access() -> i32 {
+        let pair = (1, (2, 3));
+        pair.0 + pair.1 .0 * pair.1 .1
+    }
+
+    let expression = Interpreter::execute_function(tuple_field_access__function_builder()).unwrap();
+
+    assert_eq!(tuple_field_access(), 7);
+    assert_eq!(expression, 7.into());
+}
+
+#[test]
+pub fn test_tuple_destructuring() {
+    #[define_function_builder]
+    fn tuple_destructuring() -> (i32, i32) {
+        let (mut a, mut b) = (10, 4);
+
+        while b != 0 {
+            (a, b) = (b, a - (a / b) * b);
+        }
+
+        let (gcd, _) = (a, b);
+        (gcd, a * 2)
+    }
+
+    let expression =
+        Interpreter::execute_function(tuple_destructuring__function_builder()).unwrap();
+
+    assert_eq!(tuple_destructuring(), (2, 4));
+    assert_eq!(expression, (2, 4).into());
+}
+
+#[test]
+pub fn test_tuple_return() {
+    #[define_function_builder]
+    fn min_max(vector: Vec<usize>, start: usize, end: usize) -> (usize, usize) {
+        if end - start == 1 {
+            return (vector[start], vector[start]);
+        }
+
+        let middle = (start + end) / 2;
+        let (left_min, left_max) = min_max(vector.clone(), start, middle);
+        let (right_min, right_max) = min_max(vector, middle, end);
+
+        let min = if left_min < right_min {
+            left_min
+        } else {
+            right_min
+        };
+        let max = if left_max > right_max {
+            left_max
+        } else {
+            right_max
+        };
+
+        (min, max)
+    }
+
+    let vector = vec![7usize, 3, 9, 1, 4, 8];
+    let expression = Interpreter::execute_function_with_args(
+        min_max__function_builder(),
+        vec![vector.clone().into(), 0usize.into(), 6usize.into()],
+    )
+    .unwrap();
+
+    assert_eq!(min_max(vector, 0, 6), (1, 9));
+    assert_eq!(expression, (1usize, 9usize).into());
+    assert_eq!(<(usize, usize)>::try_from(expression).unwrap(), (1, 9));
+}
+
+#[test]
+pub fn test_tuple_match() {
+    #[define_function_builder]
+    fn fizz_buzz(n: i32) -> i32 {
+        match (n - (n / 3) * 3, n - (n / 5) * 5) {
+            (0, 0) => 15,
+            (0, _) => 3,
+            (_, 0) => 5,
+            _ => n,
+        }
+    }
+
+    for n in [15, 9, 10, 7] {
+        let expression =
+            Interpreter::execute_function_with_args(fizz_buzz__function_builder(), vec![n.into()])
+                .unwrap();
+
+        assert_eq!(expression, fizz_buzz(n).into());
+    }
+}
@@ -0,0 +1,152 @@
+use algorithmify::{
+    expressions::{Float, Integer},
+    Expression, Interpreter,
+};
+use algorithmify_macros::define_function_builder;
+
+#[test]
+pub fn test_integer_suffixes() {
+    #[define_function_builder]
+    fn suffixes() -> (u8, i64, usize, i16) {
+        (255u8, 1_000i64, 0x10usize, -3i16)
+    }
+
+    let expression = Interpreter::execute_function(suffixes__function_builder()).unwrap();
+
+    assert_eq!(suffixes(), (255, 1000, 16, -3));
+    assert!(matches!(
+        &expression,
+        Expression::Tuple(elements) if matches!(
+            elements.as_slice(),
+            [
+                Expression::Integer(Integer::U8(255)),
+                Expression::Integer(Integer::I64(1000)),
+                Expression::Integer(Integer::Usize(16)),
+                Expression::Integer(Integer::I16(-3)),
+            ]
+        )
+    ));
+}
+
+#[test]
+pub fn test_let_annotations() {
+    #[define_function_builder]
+    fn annotations() -> (u64, Vec<u8>, f32) {
+        let big: u64 = 3;
+        let bytes: Vec<u8> = vec![1, 2];
+        let ratio: f32 = 0.5;
+        (big * 2, bytes, ratio)
+    }
+
+    let expression = Interpreter::execute_function(annotations__function_builder()).unwrap();
+
+    assert_eq!(annotations(), (6, vec![1, 2], 0.5));
+    assert!(matches!(
+        &expression,
+        Expression::Tuple(elements) if matches!(
+            elements.as_slice(),
+            [
+                Expression::Integer(Integer::U64(6)),
+                Expression::Vector(bytes),
+                Expression::Float(Float::F32(_)),
+            ] if matches!(bytes.as_slice(), [Expression::Integer(Integer::U8(1)), _])
+        )
+    ));
+}
+
+#[test]
+pub fn test_signature_typing() {
+    #[define_function_builder]
+    fn triangular(n: u32) -> u32 {
+        n * (n + 1) / 2
+    }
+
+    #[define_function_builder]
+    fn count_steps() -> u64 {
+        let mut steps = 0;
+        let total = triangular(4);
+        for _ in 0..total {
+            steps += 1;
+        }
+        steps
+    }
+
+    let expression = Interpreter::execute_function(count_steps__function_builder()).unwrap();
+
+    assert_eq!(count_steps(), 10);
+    assert!(matches!(expression, Expression::Integer(Integer::U64(10))));
+}
+
+#[test]
+pub fn test_only_fitting_literals_are_retyped() {
+    #[define_function_builder]
+    fn take_u8(x: u8) -> u8 {
+        x
+    }
+
+    let execute = |arg: Expression| {
+        Interpreter::execute_function_with_args(take_u8__function_builder(), vec![arg])
+    };
+
+    assert!(matches!(
+        execute(7.into()).unwrap(),
+        Expression::Integer(Integer::U8(7))
+    ));
+    assert!(execute(300.into()).is_err());
+    assert!(execute(300u32.into()).is_err());
+    assert!(execute((-1i64).into()).is_err());
+}
+
+#[test]
+pub fn test_mixed_i32_values_must_fit() {
+    // `offset` is a `u64` natively, which underflows when `skip` is false
+    #[define_function_builder]
+    fn below_first(v: Vec<u64>, skip: bool) -> bool {
+        let mut offset = 0;
+        if !skip {
+            offset -= 1;
+        }
+
+        v[0] > offset
+    }
+
+    let execute = |skip: bool| {
+        Interpreter::execute_function_with_args(
+            below_first__function_builder(),
+            vec![vec![3u64].into(), skip.into()],
+        )
+    };
+
+    assert!(below_first(vec![3], true));
+    assert_eq!(execute(true).unwrap(), true.into());
+
+    let error = execute(false).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot use 'I32(-1)' as a value of the type of 'U64(3)'"
+    );
+}
+
+#[test]
+pub fn test_large_unsigned_values() {
+    #[define_function_builder]
+    fn describe_large(value: u64, limit: u64) -> (String, bool, bool) {
+        let range = limit..value + 1;
+        (value.to_string(), range.contains(&value), value > limit)
+    }
+
+    let value = u64::MAX - 1;
+    let limit = 5;
+
+    let expression = Interpreter::execute_function_with_args(
+        describe_large__function_builder(),
+        vec![value.into(), limit.into()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        describe_large(value, limit),
+        ("18446744073709551614".to_owned(), true, true)
+    );
+    assert_eq!(expression, describe_large(value, limit).into());
+}
@@ -1,3 +1,14 @@
+// The specifications are written in the subset of Rust that the builders can
+// interpret, which is not always the most idiomatic one.
+#![allow(
+    clippy::assign_op_pattern,
+    clippy::bool_assert_comparison,
+    clippy::let_and_return,
+    clippy::manual_swap,
+    clippy::needless_range_loop,
+    clippy::nonminimal_bool,
+    clippy::useless_vec
+)]
 
-mod expressions;
 mod algorithms;
+mod expressions;
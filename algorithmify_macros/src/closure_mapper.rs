@@ -0,0 +1,94 @@
+use std::collections::BTreeSet;
+
+use proc_macro::TokenTree;
+
+use crate::{
+    expression_mapper::{map_expression, ExpressionMapping},
+    pattern_mapper::map_single_pattern,
+    token_iterator::TokenIterator,
+    type_mapper::map_type,
+};
+
+/// Whether the tokens ahead start a closure, like `|x| x + 1` or `move || 0`.
+pub(crate) fn is_closure_ahead(iterator: &TokenIterator) -> bool {
+    matches!(
+        iterator.peek(),
+        Some(TokenTree::Punct(punctuation)) if punctuation.as_char() == '|'
+    ) || matches!(iterator.peek(), Some(TokenTree::Ident(ident)) if ident.to_string() == "move")
+}
+
+pub(crate) fn map_closure(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    iterator.try_get_next_token("move");
+    iterator.try_get_next_token("|")?;
+
+    let mut params = Vec::new();
+
+    while iterator.try_get_next_token("|").is_none() {
+        // borrowed parameters like `&x` are bound to the value
+        iterator.try_get_next_token("&");
+        params.push(map_single_pattern(iterator)?);
+
+        if iterator.try_get_next_token(":").is_some() {
+            map_type(iterator)?;
+        }
+
+        iterator.try_get_next_token(",");
+    }
+
+    if iterator.try_get_next_token("-").is_some() {
+        iterator.try_get_next_token(">")?;
+        map_type(iterator)?;
+    }
+
+    let start = iterator.index;
+    // block and loop mappings come with a trailing comma, for being used as statements
+    let body = map_expression(iterator)?.mapping;
+    let body = body.trim_end().trim_end_matches(',');
+
+    let mut captures = BTreeSet::new();
+    collect_identifiers(
+        iterator.tokens_between(start, iterator.index),
+        &mut captures,
+    );
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Closure(Box::new(algorithmify::expressions::Closure {{
+            params: vec![{}],
+            body: {},
+            captures: vec![{}],
+            environment: vec![]
+        }}))",
+        params.join(","),
+        body,
+        captures
+            .iter()
+            .map(|capture| format!("\"{}\".to_owned()", capture))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+/// Collects every identifier in the body of a closure, which is a superset of the
+/// variables it captures.
+fn collect_identifiers(tokens: &[TokenTree], identifiers: &mut BTreeSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                identifiers.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => {
+                collect_identifiers(&group.stream().into_iter().collect::<Vec<_>>(), identifiers)
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(crate) fn is_closure_trait(name: &str) -> bool {
+    matches!(name, "Fn" | "FnMut" | "FnOnce")
+}
@@ -1,7 +1,8 @@
-use proc_macro::TokenTree;
+use proc_macro::{Delimiter, TokenTree};
 
 use crate::{
     expression_mapper::{alt, map_block, map_expression, map_statements, ExpressionMapping},
+    pattern_mapper::map_pattern,
     token_iterator::TokenIterator,
 };
 
@@ -17,7 +18,10 @@ pub(crate) fn map_if_condition(iterator: &mut TokenIterator) -> Option<Expressio
     };
 
     let else_clause = if iterator.try_get_next_token("else").is_some() {
-        let else_expression = alt(iterator, &[map_block, map_if_condition])?;
+        let else_expression = alt(
+            iterator,
+            &[map_block, map_if_let_condition, map_if_condition],
+        )?;
         format!("Some({})", else_expression.mapping)
     } else {
         "None".to_owned()
@@ -42,3 +46,115 @@ pub(crate) fn map_if_condition(iterator: &mut TokenIterator) -> Option<Expressio
         needs_semicolon_unless_final: false,
     })
 }
+
+pub(crate) fn map_if_let_condition(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    iterator.try_get_next_token("if")?;
+    iterator.try_get_next_token("let")?;
+
+    let pattern = map_pattern(iterator)?;
+
+    iterator.try_get_next_token("=")?;
+
+    let expression = map_expression(iterator)?.mapping;
+
+    let statements = if let TokenTree::Group(group) = iterator.next()? {
+        map_statements(group)
+    } else {
+        return None;
+    };
+
+    let else_clause = if iterator.try_get_next_token("else").is_some() {
+        let else_expression = alt(
+            iterator,
+            &[map_block, map_if_let_condition, map_if_condition],
+        )?;
+        format!("Some({})", else_expression.mapping)
+    } else {
+        "None".to_owned()
+    };
+
+    let if_let_condition = format!(
+        "algorithmify::expressions::conditions::IfLet {{
+            statements: vec![{}],
+            else_clause: {},
+            pattern: {},
+            expression: {}
+        }}",
+        statements, else_clause, pattern, expression
+    );
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Condition(Box::new(algorithmify::expressions::conditions::Condition::IfLet({})))",
+        if_let_condition
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: false,
+    })
+}
+
+pub(crate) fn map_match(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    iterator.try_get_next_token("match")?;
+
+    let expression = map_expression(iterator)?.mapping;
+
+    let arms = match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+            let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+            let mut arms = String::new();
+
+            while iterator.peek().is_some() {
+                arms += &map_match_arm(&mut iterator)?;
+                arms += ",";
+            }
+
+            arms
+        }
+        _ => return None,
+    };
+
+    let match_condition = format!(
+        "algorithmify::expressions::conditions::Match {{
+            expression: {},
+            arms: vec![{}]
+        }}",
+        expression, arms
+    );
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Condition(Box::new(algorithmify::expressions::conditions::Condition::Match({})))",
+        match_condition
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: false,
+    })
+}
+
+fn map_match_arm(iterator: &mut TokenIterator) -> Option<String> {
+    let pattern = map_pattern(iterator)?;
+
+    let guard = if iterator.try_get_next_token("if").is_some() {
+        format!("Some({})", map_expression(iterator)?.mapping)
+    } else {
+        "None".to_owned()
+    };
+
+    iterator.try_get_next_token("=")?;
+    iterator.try_get_next_token(">")?;
+
+    let expression = map_expression(iterator)?.mapping;
+
+    iterator.try_get_next_token(",");
+
+    Some(format!(
+        "algorithmify::expressions::conditions::MatchArm {{
+            pattern: {},
+            guard: {},
+            expression: {}
+        }}",
+        pattern, guard, expression
+    ))
+}
@@ -0,0 +1,78 @@
+use proc_macro::TokenTree;
+
+use crate::{
+    expression_mapper::{map_expression, ExpressionMapping},
+    token_iterator::TokenIterator,
+};
+
+pub(crate) fn map_return(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    iterator.try_get_next_token("return")?;
+
+    let expression = map_optional_expression(iterator);
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Return(Box::new({}))",
+        expression
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+pub(crate) fn map_break(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    iterator.try_get_next_token("break")?;
+
+    let label = try_get_label(iterator);
+    let expression = map_optional_expression(iterator);
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Break({}, Box::new({}))",
+        label, expression
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+pub(crate) fn map_continue(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    iterator.try_get_next_token("continue")?;
+
+    let label = try_get_label(iterator);
+
+    let mapping = format!("algorithmify::expressions::Expression::Continue({})", label);
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+fn try_get_label(iterator: &mut TokenIterator) -> String {
+    let index = iterator.index;
+
+    if let (Some(TokenTree::Punct(apostrophe)), Some(TokenTree::Ident(identifier))) =
+        (iterator.next().cloned(), iterator.next())
+    {
+        if apostrophe.as_char() == '\'' {
+            return format!("Some(\"{}\".to_owned())", identifier);
+        }
+    }
+
+    iterator.rewind_to(index);
+    "None".to_owned()
+}
+
+fn map_optional_expression(iterator: &mut TokenIterator) -> String {
+    let index = iterator.index;
+
+    if let Some(expression) = map_expression(iterator) {
+        expression.mapping
+    } else {
+        iterator.rewind_to(index);
+        "algorithmify::expressions::Expression::Unit".to_owned()
+    }
+}
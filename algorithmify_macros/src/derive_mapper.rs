@@ -0,0 +1,277 @@
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+use crate::{
+    expression_mapper::{map_method_resolver, try_get_identifier},
+    token_iterator::TokenIterator,
+};
+
+pub(crate) fn derive_expression(stream: TokenStream) -> TokenStream {
+    let mut iterator: TokenIterator = stream.into_iter().collect::<Vec<_>>().into();
+
+    skip_attributes_and_visibility(&mut iterator);
+
+    let kind = try_get_identifier(&mut iterator);
+    let name = try_get_identifier(&mut iterator).expect("expected a type name");
+
+    let body = match iterator.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group,
+        Some(TokenTree::Punct(punctuation)) if punctuation.as_char() == '<' => {
+            panic!("Expression cannot be derived for generic types")
+        }
+        other => panic!("expected a braced body, got '{:?}'", other),
+    };
+
+    let conversions = match kind.as_deref() {
+        Some("struct") => build_struct_conversions(&name, &map_field_names(body)),
+        Some("enum") => build_enum_conversions(&name, &map_variants(body)),
+        _ => panic!("Expression can only be derived for structs and enums"),
+    };
+
+    conversions.parse().unwrap()
+}
+
+fn skip_attributes_and_visibility(iterator: &mut TokenIterator) {
+    loop {
+        let index = iterator.index;
+
+        if iterator.try_get_next_token("#").is_some() {
+            iterator.next();
+            continue;
+        }
+
+        if iterator.try_get_next_token("pub").is_some() {
+            if let Some(TokenTree::Group(group)) = iterator.peek() {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    iterator.next();
+                }
+            }
+            continue;
+        }
+
+        iterator.rewind_to(index);
+        return;
+    }
+}
+
+/// Reads the name of each variant and the number of elements of its payload.
+fn map_variants(group: &proc_macro::Group) -> Vec<(String, usize)> {
+    let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+    let mut variants = Vec::new();
+
+    while iterator.peek().is_some() {
+        skip_attributes_and_visibility(&mut iterator);
+
+        let variant = try_get_identifier(&mut iterator).expect("expected a variant name");
+
+        let payload_length = match iterator.peek() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                let length = count_tuple_elements(group);
+                iterator.next();
+                length
+            }
+            Some(TokenTree::Group(_)) => {
+                panic!("Expression cannot be derived for enums with struct variants")
+            }
+            _ => 0,
+        };
+
+        variants.push((variant, payload_length));
+
+        if iterator.try_get_next_token(",").is_none() && iterator.peek().is_some() {
+            panic!("expected a , after variant, got '{:?}'", iterator.peek());
+        }
+    }
+
+    variants
+}
+
+fn count_tuple_elements(group: &proc_macro::Group) -> usize {
+    let mut depth = 0;
+    let mut count = 0;
+    let mut element_started = false;
+
+    for token in group.stream() {
+        match &*token.to_string() {
+            "<" => depth += 1,
+            ">" => depth -= 1,
+            "," if depth == 0 => {
+                count += 1;
+                element_started = false;
+                continue;
+            }
+            _ => {}
+        }
+        element_started = true;
+    }
+
+    if element_started {
+        count + 1
+    } else {
+        count
+    }
+}
+
+fn map_field_names(group: &proc_macro::Group) -> Vec<String> {
+    let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+    let mut fields = Vec::new();
+
+    while iterator.peek().is_some() {
+        skip_attributes_and_visibility(&mut iterator);
+
+        fields.push(try_get_identifier(&mut iterator).expect("expected a field name"));
+        iterator
+            .try_get_next_token(":")
+            .expect("expected a : after the field name");
+
+        // skip the field type, whose generic arguments may contain commas as well
+        let mut depth = 0;
+        while let Some(token) = iterator.next() {
+            match &*token.to_string() {
+                "<" => depth += 1,
+                ">" => depth -= 1,
+                "," if depth == 0 => break,
+                _ => {}
+            }
+        }
+    }
+
+    fields
+}
+
+fn build_struct_conversions(name: &str, fields: &[String]) -> String {
+    let into_fields = fields
+        .iter()
+        .map(|field| format!("(\"{}\".to_owned(), value.{}.into())", field, field))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let from_fields = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: structure.take_field(\"{}\")?.try_into()?",
+                field, field
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let methods = map_method_resolver(name);
+
+    format!(
+        r###"
+        impl From<{name}> for algorithmify::Expression {{
+            fn from(value: {name}) -> Self {{
+                algorithmify::Expression::Struct(algorithmify::expressions::Struct {{
+                    name: "{name}".to_owned(),
+                    fields: vec![{into_fields}],
+                    methods: {methods},
+                }})
+            }}
+        }}
+
+        impl TryFrom<algorithmify::Expression> for {name} {{
+            type Error = algorithmify::anyhow::Error;
+
+            fn try_from(expression: algorithmify::Expression) -> algorithmify::anyhow::Result<Self> {{
+                match expression {{
+                    algorithmify::Expression::Struct(mut structure) if structure.name == "{name}" => {{
+                        Ok(Self {{ {from_fields} }})
+                    }}
+                    other => Err(algorithmify::anyhow::anyhow!(
+                        "Cannot convert '{{:?}}' into {name}",
+                        other
+                    )),
+                }}
+            }}
+        }}
+    "###
+    )
+}
+
+fn build_enum_conversions(name: &str, variants: &[(String, usize)]) -> String {
+    let into_arms = variants
+        .iter()
+        .map(|(variant, payload_length)| {
+            let elements = (0..*payload_length)
+                .map(|index| format!("element{}", index))
+                .collect::<Vec<_>>();
+
+            let pattern = if elements.is_empty() {
+                String::new()
+            } else {
+                format!("({})", elements.join(","))
+            };
+
+            let payload = elements
+                .iter()
+                .map(|element| format!("{}.into()", element))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            format!(
+                r#"{name}::{variant}{pattern} => algorithmify::Expression::Enum(algorithmify::expressions::Enum {{
+                    name: "{name}".to_owned(),
+                    variant: "{variant}".to_owned(),
+                    payload: vec![{payload}],
+                }}),"#
+            )
+        })
+        .collect::<String>();
+
+    let from_arms = variants
+        .iter()
+        .map(|(variant, payload_length)| {
+            let constructor = if *payload_length == 0 {
+                String::new()
+            } else {
+                let elements = (0..*payload_length)
+                    .map(|_| "payload.next().unwrap().try_into()?")
+                    .collect::<Vec<_>>();
+                format!("({})", elements.join(","))
+            };
+
+            format!(
+                r#""{variant}" if enumeration.payload.len() == {payload_length} => {{
+                    #[allow(unused_mut, unused_variables)]
+                    let mut payload = enumeration.payload.into_iter();
+                    Ok(Self::{variant}{constructor})
+                }}"#
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        r###"
+        impl From<{name}> for algorithmify::Expression {{
+            fn from(value: {name}) -> Self {{
+                match value {{
+                    {into_arms}
+                }}
+            }}
+        }}
+
+        impl TryFrom<algorithmify::Expression> for {name} {{
+            type Error = algorithmify::anyhow::Error;
+
+            fn try_from(expression: algorithmify::Expression) -> algorithmify::anyhow::Result<Self> {{
+                match expression {{
+                    algorithmify::Expression::Enum(enumeration) if enumeration.name == "{name}" => {{
+                        match &*enumeration.variant.clone() {{
+                            {from_arms}
+                            _ => Err(algorithmify::anyhow::anyhow!(
+                                "Cannot convert '{{:?}}' into {name}",
+                                enumeration
+                            )),
+                        }}
+                    }}
+                    other => Err(algorithmify::anyhow::anyhow!(
+                        "Cannot convert '{{:?}}' into {name}",
+                        other
+                    )),
+                }}
+            }}
+        }}
+    "###
+    )
+}
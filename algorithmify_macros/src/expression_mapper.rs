@@ -1,10 +1,14 @@
 use std::str::FromStr;
 
-use proc_macro::{Delimiter, TokenTree};
+use proc_macro::{Delimiter, Spacing, TokenTree};
 
 use crate::{
-    condition_mapper::map_if_condition,
-    loop_mapper::{map_for_loop, map_while_loop},
+    closure_mapper::map_closure,
+    condition_mapper::{map_if_condition, map_if_let_condition, map_match},
+    control_flow_mapper::{map_break, map_continue, map_return},
+    format_mapper::map_format,
+    loop_mapper::{map_for_loop, map_infinite_loop, map_while_let_loop, map_while_loop},
+    scope::{is_callable, self_type, with_block_scope},
     statement_mapper::map_statement,
     token_iterator::TokenIterator,
 };
@@ -15,10 +19,10 @@ pub(crate) struct ExpressionMapping {
     pub(crate) needs_semicolon_unless_final: bool,
 }
 
-pub(crate) fn alt(
+pub(crate) fn alt<T>(
     iterator: &mut TokenIterator,
-    functions: &[fn(&mut TokenIterator) -> Option<ExpressionMapping>],
-) -> Option<ExpressionMapping> {
+    functions: &[fn(&mut TokenIterator) -> Option<T>],
+) -> Option<T> {
     let start_index = iterator.index;
 
     for function in functions {
@@ -37,160 +41,282 @@ pub(crate) fn map_statements(body: &proc_macro::Group) -> String {
     let body: Vec<TokenTree> = body.stream().into_iter().collect::<Vec<_>>();
     let mut iterator: TokenIterator = body.into();
 
-    let mut body = String::new();
-    while iterator.peek().is_some() {
-        map_statement(&mut body, &mut iterator);
-    }
-    body
+    with_block_scope(|| {
+        let mut body = String::new();
+        while iterator.peek().is_some() {
+            map_statement(&mut body, &mut iterator);
+        }
+        body
+    })
 }
 
-fn map_first_tier_precedence_expression(iterator: &mut TokenIterator) -> Option<String> {
-    let mut index = iterator.index;
-
-    if let Some(mut lhs) = map_term(iterator) {
-        index = iterator.index;
+/// Maps a term followed by any number of `as` casts, which bind tighter than binary
+/// operators but looser than unary ones.
+fn map_cast_expression(iterator: &mut TokenIterator) -> Option<String> {
+    let mut expression = map_term(iterator)?;
+
+    loop {
+        let index = iterator.index;
+
+        let target = iterator
+            .try_get_next_token("as")
+            .and_then(|_| try_get_identifier(iterator))
+            .and_then(|target| map_cast_type(&target));
+
+        match target {
+            Some(target) => {
+                expression = format!(
+                    "algorithmify::expressions::Expression::Cast(Box::new(algorithmify::expressions::Cast {{
+                        expression: {},
+                        target: algorithmify::expressions::CastType::{}
+                    }}))",
+                    expression, target
+                );
+            }
+            None => {
+                iterator.rewind_to(index);
+                return Some(expression);
+            }
+        }
+    }
+}
 
-        while let Some(operator) = iterator.next().map(|t| t.to_string()) {
-            let rhs = map_term(iterator);
+fn map_cast_type(target: &str) -> Option<&'static str> {
+    Some(match target {
+        "i8" => "I8",
+        "i16" => "I16",
+        "i32" => "I32",
+        "i64" => "I64",
+        "isize" => "Isize",
+        "u8" => "U8",
+        "u16" => "U16",
+        "u32" => "U32",
+        "u64" => "U64",
+        "usize" => "Usize",
+        "f32" => "F32",
+        "f64" => "F64",
+        "char" => "Char",
+        _ => return None,
+    })
+}
 
-            if let Some(rhs) = rhs {
-                lhs = match &*operator {
-                    "*" => map_multiplication(lhs, rhs),
-                    "/" => map_division(lhs, rhs),
-                    "&" => map_bitwise_and(lhs, rhs),
-                    "|" => map_bitwise_or(lhs, rhs),
-                    _ => break,
-                };
+/// The operators spelled with more than one punctuation character.
+const COMPOUND_OPERATORS: &[&str] = &[
+    "<<=", ">>=", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||", "+=", "-=", "*=", "/=", "%=",
+    "^=", "&=", "|=",
+];
+
+/// Reads an operator, joining punctuation spelled together like `<<` or `&&` into a
+/// single operator only when it forms one, so `a*-1` reads `*` and leaves `-1`.
+pub(crate) fn next_operator(iterator: &mut TokenIterator) -> Option<String> {
+    let (mut operator, mut spacing) = match iterator.next() {
+        Some(TokenTree::Punct(punctuation)) => {
+            (punctuation.as_char().to_string(), punctuation.spacing())
+        }
+        _ => return None,
+    };
 
-                index = iterator.index;
-            } else {
-                break;
-            }
+    while spacing == Spacing::Joint {
+        let (candidate, next_spacing) = match iterator.peek() {
+            Some(TokenTree::Punct(punctuation)) => (
+                format!("{}{}", operator, punctuation.as_char()),
+                punctuation.spacing(),
+            ),
+            _ => break,
+        };
+
+        if !COMPOUND_OPERATORS
+            .iter()
+            .any(|compound| compound.starts_with(&candidate))
+        {
+            break;
         }
 
-        iterator.rewind_to(index);
-        return Some(lhs);
+        iterator.next();
+        operator = candidate;
+        spacing = next_spacing;
     }
 
-    iterator.rewind_to(index);
-    None
+    Some(operator)
 }
 
-fn map_second_tier_precedence_expression(iterator: &mut TokenIterator) -> Option<String> {
-    let mut index = iterator.index;
+/// Maps the two operands of a binary operator into an operation.
+type MapOperation = fn(String, String) -> String;
 
-    if let Some(mut lhs) = map_first_tier_precedence_expression(iterator) {
-        index = iterator.index;
+/// Maps a chain of left associative operators from the same precedence tier, whose
+/// operands are expressions from the tier that binds tighter.
+fn map_binary_tier(
+    iterator: &mut TokenIterator,
+    operand: fn(&mut TokenIterator) -> Option<String>,
+    operators: &[(&str, MapOperation)],
+) -> Option<String> {
+    let mut lhs = operand(iterator)?;
+    let mut index = iterator.index;
 
-        while let Some(operator) = iterator.next().map(|t| t.to_string()) {
-            let rhs = map_first_tier_precedence_expression(iterator);
-            if let Some(rhs) = rhs {
-                lhs = match &*operator {
-                    "+" => map_addition(lhs, rhs),
-                    "-" => map_substraction(lhs, rhs),
-                    _ => break,
-                };
+    while let Some(operator) = next_operator(iterator) {
+        let map_operation = match operators.iter().find(|(symbol, _)| *symbol == operator) {
+            Some((_, map_operation)) => map_operation,
+            None => break,
+        };
 
-                index = iterator.index;
-            } else {
-                break;
-            }
+        match operand(iterator) {
+            Some(rhs) => lhs = map_operation(lhs, rhs),
+            None => break,
         }
 
-        iterator.rewind_to(index);
-        return Some(lhs);
+        index = iterator.index;
     }
 
     iterator.rewind_to(index);
-    None
+    Some(lhs)
 }
 
-fn map_third_tier_precedence_expression(iterator: &mut TokenIterator) -> Option<String> {
-    let mut index = iterator.index;
+fn map_multiplicative_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_cast_expression,
+        &[
+            ("*", map_multiplication),
+            ("/", map_division),
+            ("%", map_remainder),
+        ],
+    )
+}
 
-    if let Some(mut lhs) = map_second_tier_precedence_expression(iterator) {
-        index = iterator.index;
+fn map_additive_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_multiplicative_expression,
+        &[("+", map_addition), ("-", map_substraction)],
+    )
+}
 
-        while let Some(operator) = iterator.next_nth_string(2) {
-            let rhs = map_second_tier_precedence_expression(iterator);
-            if let Some(rhs) = rhs {
-                lhs = match &*operator {
-                    "==" => map_eq(lhs, rhs),
-                    "!=" => map_ne(lhs, rhs),
-                    ">=" => map_gte(lhs, rhs),
-                    "<=" => map_lte(lhs, rhs),
-                    _ => break,
-                };
-
-                index = iterator.index;
-            } else {
-                break;
-            }
-        }
+fn map_shift_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_additive_expression,
+        &[("<<", map_left_shift), (">>", map_right_shift)],
+    )
+}
 
-        iterator.rewind_to(index);
+fn map_bitwise_and_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(iterator, map_shift_expression, &[("&", map_bitwise_and)])
+}
 
-        while let Some(operator) = iterator.next().map(|t| t.to_string()) {
-            let rhs = map_third_tier_precedence_expression(iterator);
-            if let Some(rhs) = rhs {
-                lhs = match &*operator {
-                    ">" => map_gt(lhs, rhs),
-                    "<" => map_lt(lhs, rhs),
-                    _ => break,
-                };
+fn map_bitwise_xor_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_bitwise_and_expression,
+        &[("^", map_bitwise_xor)],
+    )
+}
 
-                index = iterator.index;
-            } else {
-                break;
-            }
-        }
+fn map_bitwise_or_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_bitwise_xor_expression,
+        &[("|", map_bitwise_or)],
+    )
+}
 
-        iterator.rewind_to(index);
-        return Some(lhs);
-    }
+fn map_comparison_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_bitwise_or_expression,
+        &[
+            ("==", map_eq),
+            ("!=", map_ne),
+            (">=", map_gte),
+            ("<=", map_lte),
+            (">", map_gt),
+            ("<", map_lt),
+        ],
+    )
+}
 
-    iterator.rewind_to(index);
-    None
+fn map_logical_and_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_comparison_expression,
+        &[("&&", map_logical_and)],
+    )
 }
 
-fn map_fourth_tier_precedence_expression(iterator: &mut TokenIterator) -> Option<String> {
-    let mut index = iterator.index;
+fn map_logical_expression(iterator: &mut TokenIterator) -> Option<String> {
+    map_binary_tier(
+        iterator,
+        map_logical_and_expression,
+        &[("||", map_logical_or)],
+    )
+}
 
-    if let Some(mut lhs) = map_third_tier_precedence_expression(iterator) {
-        index = iterator.index;
+fn map_operator_expression(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let index = iterator.index;
+    let lhs = map_logical_expression(iterator);
 
-        while let Some(operator) = iterator.next_nth_string(2) {
-            let rhs = map_third_tier_precedence_expression(iterator);
-            if let Some(rhs) = rhs {
-                lhs = match &*operator {
-                    "&&" => map_logical_and(lhs, rhs),
-                    "||" => map_logical_or(lhs, rhs),
-                    _ => break,
-                };
+    if lhs.is_none() {
+        iterator.rewind_to(index);
+    }
 
-                index = iterator.index;
-            } else {
-                break;
-            }
+    let index = iterator.index;
+
+    let mapping = match map_range_bounds(iterator, lhs.clone()) {
+        Some(range) => format!(
+            "algorithmify::expressions::Expression::Range(Box::new({}))",
+            range
+        ),
+        None => {
+            iterator.rewind_to(index);
+            lhs?
         }
+    };
 
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+/// Maps a range like `a..b`, `a..=b` or `..b`, which binds looser than any operator.
+pub(crate) fn map_range(iterator: &mut TokenIterator) -> Option<String> {
+    let index = iterator.index;
+    let start = map_logical_expression(iterator);
+
+    if start.is_none() {
         iterator.rewind_to(index);
-        return Some(lhs);
     }
 
-    iterator.rewind_to(index);
-    None
+    map_range_bounds(iterator, start)
 }
 
-fn map_operator_expression(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
-    if let Some(mapping) = map_fourth_tier_precedence_expression(iterator) {
-        Some(ExpressionMapping {
-            mapping,
-            needs_semicolon_unless_final: true,
-        })
-    } else {
-        None
+fn map_range_bounds(iterator: &mut TokenIterator, start: Option<String>) -> Option<String> {
+    iterator.try_get_next_token(".")?;
+    iterator.try_get_next_token(".")?;
+
+    let inclusive = iterator.try_get_next_token("=").is_some();
+
+    let index = iterator.index;
+    let end = map_logical_expression(iterator);
+
+    if end.is_none() {
+        iterator.rewind_to(index);
     }
+
+    let bound = |bound: Option<String>| match bound {
+        Some(bound) => format!("Some({})", bound),
+        None => "None".to_owned(),
+    };
+
+    Some(format!(
+        "algorithmify::expressions::Range {{
+            start: {},
+            end: {},
+            inclusive: {}
+        }}",
+        bound(start),
+        bound(end),
+        inclusive
+    ))
 }
 
 fn map_scalar_expression(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
@@ -237,26 +363,111 @@ fn map_vec_sequence(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
     })
 }
 
-fn map_method_call(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
-    let expression = map_chainable_expressions(iterator)?;
-
+fn map_method_call(iterator: &mut TokenIterator, expression: &str) -> Option<String> {
     iterator.try_get_next_token(".")?;
 
     let method = try_get_identifier(iterator)?;
 
+    // the interpreter doesn't need the type arguments of calls like `collect::<Vec<_>>()`
+    if iterator.try_get_next_token(":").is_some() {
+        iterator.try_get_next_token(":")?;
+        try_get_generic_arguments(iterator)?;
+    }
+
     match iterator.next()? {
         TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
             let args = map_comma_separated_expressions(group)?;
 
-            let mapping = format!(
+            Some(format!(
                 "algorithmify::expressions::Expression::MethodCall(algorithmify::expressions::MethodCall{{
                     expression: Box::new({}),
                     method: \"{}\".to_owned(),
                     args: vec![{}]
                 }})",
-                expression.mapping, method, args
+                expression, method, args
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn map_field_access(iterator: &mut TokenIterator, expression: &str) -> Option<String> {
+    iterator.try_get_next_token(".")?;
+
+    // nested tuple fields like `pair.0.1` are tokenized as a single float literal
+    let mut fields = match iterator.next()? {
+        TokenTree::Ident(field) => vec![field.to_string()],
+        TokenTree::Literal(literal) => literal
+            .to_string()
+            .split('.')
+            .map(|field| field.parse::<usize>().ok().map(|field| field.to_string()))
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+
+    let last = fields.pop()?;
+    let expression = fields
+        .iter()
+        .fold(expression.to_owned(), |expression, field| {
+            format!(
+                "algorithmify::expressions::Expression::FieldAccessExpression({})",
+                map_field_access_struct(&expression, field)
+            )
+        });
+
+    Some(map_field_access_struct(&expression, &last))
+}
+
+fn map_field_access_struct(expression: &str, field: &str) -> String {
+    format!(
+        "algorithmify::expressions::FieldAccessExpression {{
+            expression: Box::new({}),
+            field: \"{}\".to_owned()
+        }}",
+        expression, field
+    )
+}
+
+fn map_index(iterator: &mut TokenIterator, expression: &str) -> Option<String> {
+    match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+            let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+            let index = map_expression(&mut iterator)?;
+
+            if iterator.next().is_some() {
+                return None;
+            }
+
+            Some(format!(
+                "algorithmify::expressions::IndexedAccessExpression {{
+                    expression: Box::new({}),
+                    index: Box::new({})
+                }}",
+                expression, index.mapping
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn map_tuple(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+            let is_tuple = group.stream().into_iter().any(
+                |token| matches!(token, TokenTree::Punct(punctuation) if punctuation.as_char() == ','),
             );
 
+            let mapping = if group.stream().is_empty() {
+                "algorithmify::expressions::Expression::Unit".to_owned()
+            } else if is_tuple {
+                format!(
+                    "algorithmify::expressions::Expression::Tuple(vec![{}])",
+                    map_comma_separated_expressions(group)?
+                )
+            } else {
+                return None;
+            };
+
             Some(ExpressionMapping {
                 mapping,
                 needs_semicolon_unless_final: true,
@@ -266,18 +477,103 @@ fn map_method_call(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
     }
 }
 
-fn map_function_call(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
-    let identifier = try_get_identifier(iterator)?;
+/// The result of a postfix operator. Indices and field accesses are kept apart, since
+/// they are also the places assignments write to.
+enum Postfix {
+    Index(String),
+    Field(String),
+    Other(String),
+}
+
+impl Postfix {
+    fn into_expression(self) -> String {
+        match self {
+            Postfix::Index(index) => format!(
+                "algorithmify::expressions::Expression::IndexedAccessExpression({})",
+                index
+            ),
+            Postfix::Field(field) => format!(
+                "algorithmify::expressions::Expression::FieldAccessExpression({})",
+                field
+            ),
+            Postfix::Other(expression) => expression,
+        }
+    }
+}
+
+fn map_postfix_operator(iterator: &mut TokenIterator, expression: &str) -> Option<Postfix> {
+    let index = iterator.index;
+
+    if let Some(method_call) = map_method_call(iterator, expression) {
+        return Some(Postfix::Other(method_call));
+    }
+
+    iterator.rewind_to(index);
+
+    if let Some(field_access) = map_field_access(iterator, expression) {
+        return Some(Postfix::Field(field_access));
+    }
+
+    iterator.rewind_to(index);
+
+    if let Some(indexed_access) = map_index(iterator, expression) {
+        return Some(Postfix::Index(indexed_access));
+    }
+
+    iterator.rewind_to(index);
+
+    if iterator.try_get_next_token("?").is_some() {
+        return Some(Postfix::Other(format!(
+            "algorithmify::expressions::Expression::Try(Box::new({}))",
+            expression
+        )));
+    }
+
+    None
+}
+
+fn map_postfix_operators(iterator: &mut TokenIterator, mut expression: String) -> String {
+    while let Some(postfix) = map_postfix_operator(iterator, &expression) {
+        expression = postfix.into_expression();
+    }
+
+    expression
+}
+
+/// Maps the place on the left of an assignment, like `grid[i][j]` or `edges[k].weight`,
+/// which has to end with an index or a field access.
+pub(crate) fn map_place(iterator: &mut TokenIterator) -> Option<String> {
+    let mut expression = map_primary_term(iterator)?;
+    let mut is_place = false;
+
+    while let Some(postfix) = map_postfix_operator(iterator, &expression) {
+        is_place = matches!(postfix, Postfix::Index(_) | Postfix::Field(_));
+        expression = postfix.into_expression();
+    }
+
+    is_place.then_some(expression)
+}
+
+fn map_variant_constructor(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let constructor = match &*try_get_identifier(iterator)? {
+        "Some" => "Optional(Some",
+        "Ok" => "Result(Ok",
+        "Err" => "Result(Err",
+        _ => return None,
+    };
+
     match iterator.next()? {
         TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
-            let expressions = map_comma_separated_expressions(group)?;
+            let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+            let expression = map_expression(&mut iterator)?.mapping;
+
+            if iterator.next().is_some() {
+                return None;
+            }
 
             let mapping = format!(
-                "algorithmify::expressions::Expression::FunctionCall(algorithmify::expressions::FunctionCall{{
-                    builder: {}__function_builder, 
-                    params: vec![{}]
-                }})",
-                identifier, expressions
+                "algorithmify::expressions::Expression::{}(Box::new({})))",
+                constructor, expression
             );
 
             Some(ExpressionMapping {
@@ -289,6 +585,220 @@ fn map_function_call(iterator: &mut TokenIterator) -> Option<ExpressionMapping>
     }
 }
 
+/// Reads a path to an enum variant like `Color::Red`, returning the path as written along
+/// with the enum and variant names. Both have to be capitalized to tell them apart from
+/// module paths, and `Self` is the type of the `impl` block the path is in.
+pub(crate) fn try_get_enum_variant_path(
+    iterator: &mut TokenIterator,
+) -> Option<(String, String, String)> {
+    let mut path = vec![try_get_identifier(iterator)?];
+
+    while iterator.try_get_next_token(":").is_some() {
+        iterator.try_get_next_token(":")?;
+        path.push(try_get_identifier(iterator)?);
+    }
+
+    match path.as_slice() {
+        [.., name, variant]
+            if name.starts_with(char::is_uppercase) && variant.starts_with(char::is_uppercase) =>
+        {
+            let name = match &**name {
+                "Self" => self_type().unwrap_or(name.clone()),
+                _ => name.clone(),
+            };
+
+            Some((path.join("::"), name, variant.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn map_enum_variant(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let (path, name, variant) = try_get_enum_variant_path(iterator)?;
+
+    let mapping = match iterator.peek() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let payload = map_comma_separated_expressions(group)?;
+            iterator.next();
+
+            format!(
+                "algorithmify::expressions::Expression::Enum(algorithmify::expressions::Enum {{
+                    name: \"{}\".to_owned(),
+                    variant: \"{}\".to_owned(),
+                    payload: vec![{}]
+                }})",
+                name, variant, payload
+            )
+        }
+        // a path without a payload can be a unit variant as well as an associated
+        // constant like `Limits::MAX`, so it's left to the native code to evaluate it
+        _ => map_native_path(&path),
+    };
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+/// The value of a path like `Color::Red` or `Limits::MAX` evaluated by the native code,
+/// which needs its type to be convertible into an `Expression`.
+pub(crate) fn map_native_path(path: &str) -> String {
+    format!("algorithmify::expressions::Expression::from({})", path)
+}
+
+fn map_struct_literal(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let name = try_get_identifier(iterator)?;
+
+    // struct names are capitalized, which keeps conditions like `if found { .. }` from
+    // being taken for a struct literal
+    if !name.starts_with(char::is_uppercase) {
+        return None;
+    }
+
+    let group = match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => group,
+        _ => return None,
+    };
+
+    let tokens = group.stream().into_iter().collect::<Vec<_>>();
+
+    // and only braces starting with `field:` or `field,` are struct literal bodies, so
+    // that the block of `while i < MAX { .. }` isn't either
+    match tokens.as_slice() {
+        [TokenTree::Ident(_), TokenTree::Punct(punctuation), ..]
+            if punctuation.as_char() == ',' || punctuation.to_string() == ":" => {}
+        _ => return None,
+    }
+
+    let mut iterator: TokenIterator = tokens.into();
+    let mut fields = String::new();
+
+    while iterator.peek().is_some() {
+        let field = try_get_identifier(&mut iterator)?;
+
+        let expression = if iterator.try_get_next_token(":").is_some() {
+            map_expression(&mut iterator)?.mapping
+        } else {
+            format!(
+                "algorithmify::expressions::Expression::Reference(algorithmify::expressions::Reference::Variable(\"{}\".to_owned()))",
+                field
+            )
+        };
+
+        fields += &format!("(\"{}\".to_owned(), {}),", field, expression);
+
+        if iterator.try_get_next_token(",").is_none() && iterator.peek().is_some() {
+            return None;
+        }
+    }
+
+    // within an `impl` block, `Self { .. }` is a literal of the type of the block
+    let type_name = match &*name {
+        "Self" => self_type().unwrap_or(name.clone()),
+        _ => name.clone(),
+    };
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Struct(algorithmify::expressions::Struct {{
+            name: \"{}\".to_owned(),
+            fields: vec![{}],
+            methods: {}
+        }})",
+        type_name,
+        fields,
+        map_method_resolver(&name)
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+/// The resolver of the interpreted methods of a type, which finds none of them unless
+/// the type has an `impl` block with `define_method_builders`.
+pub(crate) fn map_method_resolver(type_name: &str) -> String {
+    format!(
+        "Some({{
+            #[allow(unused_imports)]
+            use algorithmify::expressions::structure::MethodBuilders as _;
+            {}::__method_builder
+        }})",
+        type_name
+    )
+}
+
+fn map_function_call(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let mut path = vec![try_get_identifier(iterator)?];
+
+    while iterator.try_get_next_token(":").is_some() {
+        iterator.try_get_next_token(":")?;
+
+        // the interpreter doesn't need the type arguments of calls like `swap::<T>(..)`
+        if try_get_generic_arguments(iterator).is_none() {
+            path.push(try_get_identifier(iterator)?);
+        }
+    }
+
+    match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+            let expressions = map_comma_separated_expressions(group)?;
+
+            let mapping = if let Some(constructor) = map_std_constructor(&path, &expressions) {
+                constructor
+            } else if path.len() == 1 && is_callable(&path[0]) {
+                format!(
+                    "algorithmify::expressions::Expression::ClosureCall(algorithmify::expressions::ClosureCall {{
+                        expression: Box::new(algorithmify::expressions::Expression::Reference(algorithmify::expressions::Reference::Variable(\"{}\".to_owned()))),
+                        params: vec![{}]
+                    }})",
+                    path[0], expressions
+                )
+            } else {
+                // the builder lives next to the function, so `utils::swap` resolves to
+                // `utils::swap__function_builder`
+                format!(
+                    "algorithmify::expressions::Expression::FunctionCall(algorithmify::expressions::FunctionCall{{
+                        builder: {}__function_builder,
+                        params: vec![{}]
+                    }})",
+                    path.join("::"), expressions
+                )
+            };
+
+            Some(ExpressionMapping {
+                mapping,
+                needs_semicolon_unless_final: true,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Maps the constructors of `Vec` and `String`, which the interpreter represents as
+/// vectors and strings.
+fn map_std_constructor(path: &[String], args: &str) -> Option<String> {
+    let arg = args.trim_end_matches(',');
+
+    // only the prelude names are mapped, so a user path like `utils::Vec::from` is
+    // still a call to the user function
+    let [collection, constructor] = path else {
+        return None;
+    };
+
+    match (&**collection, &**constructor) {
+        ("Vec", "new" | "with_capacity") => {
+            Some("algorithmify::expressions::Expression::Vector(vec![])".to_owned())
+        }
+        ("String", "new" | "with_capacity") => {
+            Some("algorithmify::expressions::Expression::String(String::new())".to_owned())
+        }
+        ("Vec" | "String", "from") => Some(arg.to_owned()),
+        _ => None,
+    }
+}
+
 fn map_vec_shorthand(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
     iterator.try_get_next_token("vec")?;
     iterator.try_get_next_token("!")?;
@@ -300,17 +810,27 @@ fn map_vec_shorthand(iterator: &mut TokenIterator) -> Option<ExpressionMapping>
 
             let expression = map_expression(&mut iterator)?.mapping;
             iterator.try_get_next_token(";")?;
-            let repetitions = try_get::<usize>(&mut iterator)?;
+
+            let index = iterator.index;
+            let mapping = match (try_get::<usize>(&mut iterator), iterator.peek()) {
+                (Some(repetitions), None) => format!(
+                    "algorithmify::expressions::Expression::Vector(vec![{};{}])",
+                    expression, repetitions
+                ),
+                _ => {
+                    iterator.rewind_to(index);
+                    let repetitions = map_expression(&mut iterator)?.mapping;
+                    format!(
+                        "algorithmify::expressions::Expression::VectorRepetition(Box::new({}), Box::new({}))",
+                        expression, repetitions
+                    )
+                }
+            };
 
             if iterator.next().is_some() {
                 return None;
             }
 
-            let mapping = format!(
-                "algorithmify::expressions::Expression::Vector(vec![{};{}])",
-                expression, repetitions
-            );
-
             Some(ExpressionMapping {
                 mapping,
                 needs_semicolon_unless_final: false,
@@ -320,25 +840,22 @@ fn map_vec_shorthand(iterator: &mut TokenIterator) -> Option<ExpressionMapping>
     }
 }
 
-pub(crate) fn map_chainable_expressions(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
-    alt(
-        iterator,
-        &[map_vec_shorthand, map_vec_sequence, map_scalar_expression],
-    )
-}
-
 pub(crate) fn map_expression(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
     alt(
         iterator,
         &[
-            map_method_call,
-            map_function_call,
-            map_vec_shorthand,
-            map_vec_sequence,
+            map_return,
+            map_break,
+            map_continue,
+            map_if_let_condition,
             map_if_condition,
+            map_match,
             map_block,
             map_for_loop,
+            map_while_let_loop,
             map_while_loop,
+            map_infinite_loop,
+            map_closure,
             map_operator_expression,
         ],
     )
@@ -386,6 +903,22 @@ fn map_division(lhs: String, rhs: String) -> String {
     format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::Div({}, {})))", lhs, rhs)
 }
 
+fn map_remainder(lhs: String, rhs: String) -> String {
+    format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::Rem({}, {})))", lhs, rhs)
+}
+
+fn map_bitwise_xor(lhs: String, rhs: String) -> String {
+    format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::BitXor({}, {})))", lhs, rhs)
+}
+
+fn map_left_shift(lhs: String, rhs: String) -> String {
+    format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::Shl({}, {})))", lhs, rhs)
+}
+
+fn map_right_shift(lhs: String, rhs: String) -> String {
+    format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::Shr({}, {})))", lhs, rhs)
+}
+
 fn map_bitwise_and(lhs: String, rhs: String) -> String {
     format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::BitAnd({}, {})))", lhs, rhs)
 }
@@ -402,6 +935,14 @@ fn map_logical_or(lhs: String, rhs: String) -> String {
     format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::Or({}, {})))", lhs, rhs)
 }
 
+fn map_negation(operand: String) -> String {
+    format!("algorithmify::expressions::Expression::UnaryOperation(Box::new(algorithmify::expressions::UnaryOperation::Neg({})))", operand)
+}
+
+fn map_not(operand: String) -> String {
+    format!("algorithmify::expressions::Expression::UnaryOperation(Box::new(algorithmify::expressions::UnaryOperation::Not({})))", operand)
+}
+
 fn map_eq(lhs: String, rhs: String) -> String {
     format!("algorithmify::expressions::Expression::Operation(Box::new(algorithmify::expressions::Operation::Eq({}, {})))", lhs, rhs)
 }
@@ -427,26 +968,67 @@ fn map_gte(lhs: String, rhs: String) -> String {
 }
 
 pub(crate) fn map_term(iterator: &mut TokenIterator) -> Option<String> {
-    if let expression @ Some(_) = alt(
+    // borrows and dereferences are transparent to the interpreter, which works on values
+    if iterator.try_get_next_token("&").is_some() {
+        iterator.try_get_next_token("mut");
+    }
+
+    // loops over `v.iter()` bind references to the items, which the body reads as `*x`
+    while iterator.try_get_next_token("*").is_some() {}
+
+    // unary operators bind tighter than any binary operator, but looser than method
+    // calls and field accesses, so `-x.abs()` negates the result of `abs`
+    if iterator.try_get_next_token("-").is_some() {
+        let index = iterator.index;
+
+        // negative literals are mapped as such, unless a method is called on them
+        if let Some(TokenTree::Literal(literal)) = iterator.next().cloned() {
+            if let Some(number) = map_integer(&format!("-{}", literal)) {
+                if iterator.peek().map(|token| token.to_string()).as_deref() != Some(".") {
+                    return Some(number);
+                }
+            }
+        }
+
+        iterator.rewind_to(index);
+
+        return Some(map_negation(map_term(iterator)?));
+    }
+
+    if iterator.try_get_next_token("!").is_some() {
+        return Some(map_not(map_term(iterator)?));
+    }
+
+    let term = map_primary_term(iterator)?;
+
+    Some(map_postfix_operators(iterator, term))
+}
+
+fn map_primary_term(iterator: &mut TokenIterator) -> Option<String> {
+    let term = alt(
         iterator,
         &[
-            try_get_indexed_access_expression,
+            map_variant_constructor,
+            map_enum_variant,
+            map_struct_literal,
             map_function_call,
-            map_method_call,
+            map_format,
+            map_vec_shorthand,
+            map_vec_sequence,
+            map_tuple,
+            map_scalar_expression,
         ],
-    ) {
-        return expression.map(|e| e.mapping);
-    }
+    )?;
 
-    map_value(iterator)
+    Some(term.mapping)
 }
 
 fn map_value(iterator: &mut TokenIterator<'_>) -> Option<String> {
     match iterator.next()? {
         TokenTree::Ident(variable) => Some(map_reference_expression(variable)),
-        TokenTree::Literal(literal) if literal.to_string().parse::<i32>().is_ok() => {
-            Some(map_integer(literal))
-        }
+        TokenTree::Literal(literal) => map_integer(&literal.to_string())
+            .or_else(|| map_text(literal))
+            .or_else(|| map_float(literal)),
         TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
             let children = group.stream().into_iter().collect::<Vec<_>>();
             let mut iterator: TokenIterator = children.into();
@@ -459,45 +1041,6 @@ fn map_value(iterator: &mut TokenIterator<'_>) -> Option<String> {
     }
 }
 
-pub(crate) fn try_get_indexed_access(iterator: &mut TokenIterator) -> Option<String> {
-    if let TokenTree::Ident(ident) = iterator.next().cloned()? {
-        if let TokenTree::Group(group) = iterator.next()? {
-            if group.delimiter() == Delimiter::Bracket {
-                let mut iterator: TokenIterator =
-                    group.stream().into_iter().collect::<Vec<_>>().into();
-                let index = map_expression(&mut iterator)?;
-                if iterator.next().is_none() {
-                    return Some(map_indexed_reference(&ident, index.mapping));
-                }
-            }
-        }
-    }
-
-    None
-}
-
-pub(crate) fn try_get_indexed_access_expression(
-    iterator: &mut TokenIterator,
-) -> Option<ExpressionMapping> {
-    if let TokenTree::Ident(ident) = iterator.next().cloned()? {
-        if let TokenTree::Group(group) = iterator.next()? {
-            if group.delimiter() == Delimiter::Bracket {
-                let mut iterator: TokenIterator =
-                    group.stream().into_iter().collect::<Vec<_>>().into();
-                let index = map_expression(&mut iterator)?;
-                if iterator.next().is_none() {
-                    return Some(ExpressionMapping {
-                        mapping: map_indexed_reference_expression(&ident, index.mapping),
-                        needs_semicolon_unless_final: true,
-                    });
-                }
-            }
-        }
-    }
-
-    None
-}
-
 pub(crate) fn try_get<T: FromStr>(iterator: &mut TokenIterator) -> Option<T> {
     match iterator.next()? {
         TokenTree::Literal(literal) => literal.to_string().parse::<T>().ok(),
@@ -512,59 +1055,136 @@ pub(crate) fn try_get_identifier(iterator: &mut TokenIterator) -> Option<String>
     }
 }
 
-pub(crate) fn try_get_type(iterator: &mut TokenIterator) -> Option<String> {
-    iterator.try_get_next_token("&");
-    iterator.try_get_next_token("mut");
+/// Reads a list of generic arguments like `<Vec<(usize, T)>>`, including any nested
+/// ones.
+pub(crate) fn try_get_generic_arguments(iterator: &mut TokenIterator) -> Option<String> {
+    iterator.try_get_next_token("<")?;
 
-    let type_name = try_get_identifier(iterator)?;
+    let mut buffer = "<".to_owned();
+    let mut depth = 1;
 
-    match iterator.peek().cloned() {
-        Some(TokenTree::Punct(punctuation)) if punctuation.as_char() == '<' => {
-            let mut buffer = iterator.next().unwrap().to_string();
+    while depth > 0 {
+        let token = iterator.next()?.to_string();
+        match &*token {
+            "<" => depth += 1,
+            ">" => depth -= 1,
+            _ => {}
+        }
+        buffer += &token;
+    }
 
-            while let Some(token) = iterator.next() {
-                let token = token.to_string();
-                buffer += &token;
-                if token == ">" {
-                    break;
-                }
-            }
+    Some(buffer)
+}
+
+/// Maps an integer literal, honoring its suffix. Unsuffixed literals are `i32`, or
+/// `i64` if they don't fit, and take the type the native code infers for them once
+/// they are mixed with other integers or bound to a typed variable.
+pub(crate) fn map_integer(literal: &str) -> Option<String> {
+    let digits = literal.replace('_', "");
+
+    let (digits, variant) = [
+        ("i8", "I8"),
+        ("i16", "I16"),
+        ("i32", "I32"),
+        ("i64", "I64"),
+        ("isize", "Isize"),
+        ("u8", "U8"),
+        ("u16", "U16"),
+        ("u32", "U32"),
+        ("u64", "U64"),
+        ("usize", "Usize"),
+    ]
+    .iter()
+    .find_map(|(suffix, variant)| {
+        digits
+            .strip_suffix(suffix)
+            .map(|digits| (digits.to_owned(), *variant))
+    })
+    .unwrap_or((digits.clone(), ""));
+
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (-1, digits),
+        None => (1, digits.as_str()),
+    };
 
-            Some(format!("{}{}", type_name, buffer))
+    let number = sign
+        * match digits.get(..2) {
+            Some("0x") => i128::from_str_radix(&digits[2..], 16),
+            Some("0o") => i128::from_str_radix(&digits[2..], 8),
+            Some("0b") => i128::from_str_radix(&digits[2..], 2),
+            _ => digits.parse::<i128>(),
         }
-        _ => Some(type_name),
+        .ok()?;
+
+    let variant = match variant {
+        "" if i32::try_from(number).is_ok() => "I32",
+        "" => "I64",
+        variant => variant,
+    };
+
+    Some(format!(
+        "algorithmify::expressions::Expression::Integer(algorithmify::expressions::Integer::{}({}))",
+        variant, literal
+    ))
+}
+
+/// Maps string, char and byte literals, which are written back as they are.
+pub(crate) fn map_text(literal: &proc_macro::Literal) -> Option<String> {
+    let literal = literal.to_string();
+
+    if literal.starts_with('"') || literal.starts_with("r\"") || literal.starts_with("r#") {
+        Some(format!(
+            "algorithmify::expressions::Expression::String({}.to_owned())",
+            literal
+        ))
+    } else if literal.starts_with('\'') {
+        Some(format!(
+            "algorithmify::expressions::Expression::Char({})",
+            literal
+        ))
+    } else if literal.starts_with("b'") {
+        Some(format!(
+            "algorithmify::expressions::Expression::Integer(algorithmify::expressions::Integer::U8({}))",
+            literal
+        ))
+    } else {
+        None
     }
 }
 
-pub(crate) fn map_integer(literal: &proc_macro::Literal) -> String {
-    let number = literal.to_string().parse::<i32>().unwrap();
-    format!(
-        "algorithmify::expressions::Expression::Integer(algorithmify::expressions::Integer::I32({}))",
+pub(crate) fn map_float(literal: &proc_macro::Literal) -> Option<String> {
+    let literal = literal.to_string().replace('_', "");
+
+    let (number, variant) = if let Some(number) = literal.strip_suffix("f32") {
+        (number, "F32")
+    } else if let Some(number) = literal.strip_suffix("f64") {
+        (number, "F64")
+    } else {
+        (literal.as_str(), "F64")
+    };
+
+    let number = number.parse::<f64>().ok()?;
+
+    Some(format!(
+        "algorithmify::expressions::Expression::Float(algorithmify::expressions::Float::{}({:?}{}))",
+        variant,
         number,
-    )
+        variant.to_lowercase()
+    ))
 }
 
 pub(crate) fn map_reference_expression(reference: &proc_macro::Ident) -> String {
     match &*reference.to_string() {
         "true" => "algorithmify::expressions::Expression::Bool(true)".to_owned(),
         "false" => "algorithmify::expressions::Expression::Bool(false)".to_owned(),
+        "None" => "algorithmify::expressions::Expression::Optional(None)".to_owned(),
         _ => format!(
             "algorithmify::expressions::Expression::Reference({})",
-            map_reference(&reference),
+            map_reference(reference),
         ),
     }
 }
 
-pub(crate) fn map_indexed_reference_expression(
-    reference: &proc_macro::Ident,
-    index: String,
-) -> String {
-    format!(
-        "algorithmify::expressions::Expression::IndexedAccessExpression({})",
-        map_indexed_reference(&reference, index),
-    )
-}
-
 pub(crate) fn map_reference(reference: &proc_macro::Ident) -> String {
     format!(
         "algorithmify::expressions::Reference::Variable(\"{}\".to_owned())",
@@ -572,16 +1192,6 @@ pub(crate) fn map_reference(reference: &proc_macro::Ident) -> String {
     )
 }
 
-pub(crate) fn map_indexed_reference(reference: &proc_macro::Ident, index: String) -> String {
-    format!(
-        "algorithmify::expressions::IndexedAccessExpression{{
-            variable: \"{}\".to_owned(), 
-            index: Box::new({})
-        }}",
-        reference, index
-    )
-}
-
 pub(crate) fn map_comma_separated_expressions(group: &proc_macro::Group) -> Option<String> {
     let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
 
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use proc_macro::{Spacing, TokenTree};
+
+use crate::{
+    expression_mapper::{map_expression, ExpressionMapping},
+    token_iterator::TokenIterator,
+};
+
+/// Maps `format!` to the string it formats, and `print!`, `println!`, `eprint!` and
+/// `eprintln!` to the printing of that string. Only the `{}` and `{:?}` placeholders
+/// are supported, with their arguments given by position, by name or inline, and other
+/// specs like `{:>5}` make the builder fail naming the spec.
+pub(crate) fn map_format(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let name = match iterator.next()? {
+        TokenTree::Ident(ident) => ident.to_string(),
+        _ => return None,
+    };
+
+    let newline = match &*name {
+        "format" | "print" | "eprint" => false,
+        "println" | "eprintln" => true,
+        _ => return None,
+    };
+
+    iterator.try_get_next_token("!")?;
+
+    let group = match iterator.next()? {
+        TokenTree::Group(group) => group.clone(),
+        _ => return None,
+    };
+
+    let mut inner: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+
+    let format = match inner.next() {
+        Some(TokenTree::Literal(literal)) => map_format_string(&literal.to_string(), &mut inner)?,
+        // `println!()` prints an empty line
+        None => map_format_string("\"\"", &mut inner)?,
+        _ => return None,
+    };
+
+    let mapping = match &*name {
+        "format" => format!(
+            "algorithmify::expressions::Expression::Format(Box::new({}))",
+            format
+        ),
+        _ => format!(
+            "algorithmify::expressions::Expression::Print(Box::new(algorithmify::expressions::Print {{
+                format: {},
+                newline: {}
+            }}))",
+            format, newline
+        ),
+    };
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: true,
+    })
+}
+
+/// Splits the format string around its placeholders and maps the argument of each one,
+/// reading the arguments that follow the format string from `iterator`.
+fn map_format_string(literal: &str, iterator: &mut TokenIterator) -> Option<String> {
+    let mut positional = Vec::new();
+    let mut named = HashMap::new();
+
+    while iterator.try_get_next_token(",").is_some() && iterator.peek().is_some() {
+        let index = iterator.index;
+
+        // named arguments like `total = a + b`, which `a == b` isn't
+        if let (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(punctuation))) =
+            (iterator.next().cloned(), iterator.next().cloned())
+        {
+            if punctuation.as_char() == '=' && punctuation.spacing() == Spacing::Alone {
+                named.insert(name.to_string(), map_expression(iterator)?.mapping);
+                continue;
+            }
+        }
+
+        iterator.rewind_to(index);
+        positional.push(map_expression(iterator)?.mapping);
+    }
+
+    let (content, is_raw) = match literal.strip_prefix('r') {
+        Some(raw) => (raw.trim_matches('#'), true),
+        None => (literal, false),
+    };
+    let content = content.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut pieces = Vec::new();
+    let mut args = Vec::new();
+    let mut piece = String::new();
+    let mut next_position = 0;
+    let mut characters = content.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            // escapes are copied as they are, since the pieces are string literals too,
+            // including the braces of unicode escapes like `\u{7FFF}`
+            '\\' if !is_raw => {
+                piece.push(character);
+
+                match characters.next()? {
+                    'u' => {
+                        piece.push('u');
+                        for character in characters.by_ref() {
+                            piece.push(character);
+                            if character == '}' {
+                                break;
+                            }
+                        }
+                    }
+                    escaped => piece.push(escaped),
+                }
+            }
+            '{' | '}' if characters.peek() == Some(&character) => {
+                characters.next();
+                piece.push(character);
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                for character in characters.by_ref() {
+                    if character == '}' {
+                        break;
+                    }
+                    placeholder.push(character);
+                }
+
+                let (argument, spec) = match placeholder.split_once(':') {
+                    Some((argument, spec)) => (argument.trim(), spec),
+                    None => (placeholder.trim(), ""),
+                };
+
+                let expression = if argument.is_empty() {
+                    next_position += 1;
+                    positional.get(next_position - 1)?.clone()
+                } else if let Ok(position) = argument.parse::<usize>() {
+                    positional.get(position)?.clone()
+                } else {
+                    named
+                        .get(argument)
+                        .cloned()
+                        .unwrap_or_else(|| format!(
+                            "algorithmify::expressions::Expression::Reference(algorithmify::expressions::Reference::Variable(\"{}\".to_owned()))",
+                            argument
+                        ))
+                };
+
+                let style = match spec {
+                    "" => "Display",
+                    "?" => "Debug",
+                    spec => panic!(
+                        "unsupported format spec '{{:{}}}' in \"{}\", only {{}} and {{:?}} placeholders can be interpreted",
+                        spec,
+                        literal.trim_matches('"')
+                    ),
+                };
+
+                pieces.push(std::mem::take(&mut piece));
+                args.push(format!(
+                    "algorithmify::expressions::FormatArg::{}({})",
+                    style, expression
+                ));
+            }
+            _ => piece.push(character),
+        }
+    }
+
+    pieces.push(piece);
+
+    let pieces = pieces
+        .iter()
+        .map(|piece| match is_raw {
+            true => format!("{:?}.to_owned()", piece),
+            false => format!("\"{}\".to_owned()", piece),
+        })
+        .collect::<Vec<_>>();
+
+    Some(format!(
+        "algorithmify::expressions::Format {{
+            pieces: vec![{}],
+            args: vec![{}]
+        }}",
+        pieces.join(","),
+        args.join(",")
+    ))
+}
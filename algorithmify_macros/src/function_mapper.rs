@@ -1,82 +1,237 @@
 use proc_macro::{Delimiter, TokenStream, TokenTree};
 
 use crate::{
-    expression_mapper::{map_statements, try_get_identifier, try_get_type},
+    closure_mapper::is_closure_trait,
+    expression_mapper::{map_statements, try_get_identifier},
+    scope::{declare_callable, with_scope, Scope},
     token_iterator::TokenIterator,
+    type_mapper::map_type,
 };
 
 #[derive(Default, Debug)]
 struct FunctionParams {
     function_name: Option<String>,
+    function_generics: Vec<String>,
+    /// The parameters and generic parameters that hold closures.
+    callables: Vec<String>,
     function_args: Option<String>,
+    return_type: Option<String>,
     function_statements: Option<String>,
 }
 
 pub(crate) fn define_function_builder(stream: TokenStream, attrs: TokenStream) -> TokenStream {
     let trees = stream.clone().into_iter().collect::<Vec<_>>();
-    let mut params = FunctionParams::default();
-
-    for (index, tree) in trees.iter().enumerate() {
-        match tree {
-            TokenTree::Ident(identifier) if identifier.to_string() == "fn" => {
-                params.function_name = Some(trees[index + 1].to_string());
-            }
-            TokenTree::Group(body) if body.delimiter() == Delimiter::Parenthesis => {
-                params.function_args = map_args(body);
-            }
-            TokenTree::Group(body) if body.delimiter() == Delimiter::Brace => {
-                map_function_body(&mut params, body);
-            }
-            _ => {}
-        }
-    }
 
     let builder_stream = format!(
         r###"
-        fn {}__function_builder() -> algorithmify::Function {{
-            algorithmify::Function::new(
-                vec![
-                    {}
-                ],
-                vec![
-                    {}
-                ],
-                std::collections::HashMap::from([{}])
-            )
-        }}
+        {}
 
         #[allow(unused_labels)]
         #[allow(dead_code)]
     "###,
-        params.function_name.unwrap(),
-        params.function_args.unwrap_or("".to_string()),
-        params.function_statements.unwrap_or("".to_string()),
-        build_contracts(attrs)
+        map_function_builder(&trees, &build_contracts(attrs), None)
     )
     .parse()
     .unwrap();
 
-    [builder_stream, stream]
-        .into_iter()
-        .flat_map(|s| s)
-        .collect()
+    [builder_stream, stream].into_iter().flatten().collect()
 }
 
-fn map_args(body: &proc_macro::Group) -> Option<String> {
+/// Maps a function to the function that builds it. For the methods of an `impl` block,
+/// `self_type` has the name of the type and the generic parameters of the block, and
+/// the receiver becomes the first argument of the function.
+pub(crate) fn map_function_builder(
+    trees: &[TokenTree],
+    contracts: &str,
+    self_type: Option<(&str, &[String])>,
+) -> String {
+    let scope = Scope {
+        self_type: self_type.map(|(name, _)| name.to_owned()),
+        ..Default::default()
+    };
+
+    with_scope(scope, || {
+        let mut iterator: TokenIterator = trees.into();
+        let mut params = FunctionParams::default();
+
+        // the builder is as visible as the function, so that calls from other modules
+        // resolve to it
+        let mut visibility = String::new();
+        while iterator.try_get_next_token("fn").is_none() {
+            match iterator.next().expect("expected a function") {
+                TokenTree::Ident(ident) if ident.to_string() == "pub" => visibility += "pub",
+                TokenTree::Group(group) if visibility == "pub" => visibility += &group.to_string(),
+                _ => {}
+            }
+        }
+
+        params.function_name = try_get_identifier(&mut iterator);
+        params.function_generics = map_generics(&mut iterator, &mut params.callables);
+
+        let args = match iterator.next() {
+            Some(TokenTree::Group(args)) => args.clone(),
+            other => panic!("expected the function arguments, got '{:?}'", other),
+        };
+
+        if iterator.try_get_next_token("-").is_some() {
+            iterator.try_get_next_token(">");
+            params.return_type = map_type(&mut iterator);
+        }
+
+        // the body comes after the `where` clause, if there's one
+        let mut bounded_generic = None;
+        while let Some(tree) = iterator.next().cloned() {
+            match tree {
+                TokenTree::Group(body) if body.delimiter() == Delimiter::Brace => {
+                    params.function_args = map_args(
+                        &args,
+                        &mut params.callables,
+                        self_type.map(|(name, _)| name),
+                    );
+                    map_function_body(&mut params, &body);
+                    break;
+                }
+                TokenTree::Ident(ident) if is_closure_trait(&ident.to_string()) => {
+                    params.callables.extend(bounded_generic.clone());
+                }
+                TokenTree::Ident(ident)
+                    if iterator.peek().map(|t| t.to_string()).as_deref() == Some(":") =>
+                {
+                    bounded_generic = Some(ident.to_string());
+                }
+                _ => {}
+            }
+        }
+
+        let function_name = params.function_name.as_ref().unwrap();
+
+        // methods are named after their type, as in `Heap::push`, and are generic over
+        // the generic parameters of their `impl` block as well
+        let (name, generics) = match self_type {
+            Some((type_name, type_generics)) => (
+                format!("{}::{}", type_name, function_name),
+                type_generics
+                    .iter()
+                    .chain(&params.function_generics)
+                    .collect::<Vec<_>>(),
+            ),
+            None => (
+                function_name.clone(),
+                params.function_generics.iter().collect(),
+            ),
+        };
+
+        format!(
+            r###"
+            #[allow(clippy::needless_update)]
+            {} fn {}__function_builder() -> algorithmify::Function {{
+                algorithmify::Function::new(
+                    "{}".to_owned(),
+                    vec![{}],
+                    vec![
+                        {}
+                    ],
+                    {},
+                    vec![
+                        {}
+                    ],
+                    std::collections::HashMap::from([{}])
+                )
+            }}
+        "###,
+            visibility,
+            function_name,
+            name,
+            generics
+                .iter()
+                .map(|generic| format!("\"{}\".to_owned()", generic))
+                .collect::<Vec<_>>()
+                .join(","),
+            params.function_args.clone().unwrap_or("".to_string()),
+            params
+                .return_type
+                .clone()
+                .unwrap_or("algorithmify::expressions::Type::Unit".to_string()),
+            params.function_statements.clone().unwrap_or("".to_string()),
+            contracts
+        )
+    })
+}
+
+/// Reads the names of the generic parameters of the function, skipping their bounds
+/// and any lifetimes. The ones bounded by a closure trait are added to `callables`.
+pub(crate) fn map_generics(
+    iterator: &mut TokenIterator,
+    callables: &mut Vec<String>,
+) -> Vec<String> {
+    let mut generics = Vec::new();
+
+    if iterator.try_get_next_token("<").is_none() {
+        return generics;
+    }
+
+    let mut depth = 1;
+    let mut expects_name = true;
+
+    while depth > 0 {
+        let token = iterator
+            .next()
+            .expect("unclosed generic parameters")
+            .to_string();
+
+        match &*token {
+            "'" => {
+                iterator.next();
+                expects_name = false;
+            }
+            "const" => {}
+            "<" => depth += 1,
+            ">" => depth -= 1,
+            "-" => {
+                // the arrow of bounds like `F: Fn(usize) -> bool`
+                iterator.try_get_next_token(">");
+            }
+            "," if depth == 1 => expects_name = true,
+            name if is_closure_trait(name) => callables.extend(generics.last().cloned()),
+            _ if expects_name => {
+                generics.push(token);
+                expects_name = false;
+            }
+            _ => {}
+        }
+    }
+
+    generics
+}
+
+fn map_args(
+    body: &proc_macro::Group,
+    callables: &mut Vec<String>,
+    self_type: Option<&str>,
+) -> Option<String> {
     let mut iterator: TokenIterator = body.stream().into_iter().collect::<Vec<_>>().into();
 
     let mut args = Vec::new();
 
-    while let Some(_) = iterator.peek() {
+    if let Some(self_type) = self_type {
+        args.extend(map_receiver(&mut iterator, self_type));
+    }
+
+    while iterator.peek().is_some() {
         iterator.try_get_next_token("mut");
 
         let arg = try_get_identifier(&mut iterator)?;
 
         iterator.try_get_next_token(":")?;
 
-        try_get_type(&mut iterator)?;
+        let start = iterator.index;
+        let arg_type = map_type(&mut iterator)?;
 
-        args.push(format!("\"{}\".to_owned()", arg));
+        if is_closure_type(iterator.tokens_between(start, iterator.index), callables) {
+            callables.push(arg.clone());
+        }
+
+        args.push(format!("(\"{}\".to_owned(), {})", arg, arg_type));
 
         iterator.try_get_next_token(",");
     }
@@ -84,9 +239,68 @@ fn map_args(body: &proc_macro::Group) -> Option<String> {
     Some(args.join(", "))
 }
 
+/// Maps the receiver of a method, like `&self` or `&mut self`, to a `self` argument.
+fn map_receiver(iterator: &mut TokenIterator, self_type: &str) -> Option<String> {
+    let index = iterator.index;
+
+    let is_reference = iterator.try_get_next_token("&").is_some();
+    if is_reference && iterator.try_get_next_token("'").is_some() {
+        iterator.next();
+    }
+
+    let is_mutable = iterator.try_get_next_token("mut").is_some();
+
+    if iterator.try_get_next_token("self").is_none() {
+        iterator.rewind_to(index);
+        return None;
+    }
+
+    iterator.try_get_next_token(",");
+
+    let self_type = format!(
+        "algorithmify::expressions::Type::Named(\"{}\".to_owned(), vec![])",
+        self_type
+    );
+
+    let self_type = if is_reference && is_mutable {
+        format!(
+            "algorithmify::expressions::Type::MutableReference(Box::new({}))",
+            self_type
+        )
+    } else {
+        self_type
+    };
+
+    Some(format!("(\"self\".to_owned(), {})", self_type))
+}
+
+/// Whether a parameter type like `&impl Fn(usize) -> bool` or `F`, for a generic `F`
+/// bounded by a closure trait, is a closure.
+fn is_closure_type(tokens: &[TokenTree], callables: &[String]) -> bool {
+    let mut is_lifetime = false;
+
+    for token in tokens {
+        match token {
+            TokenTree::Punct(punctuation) => is_lifetime = punctuation.as_char() == '\'',
+            TokenTree::Ident(_) if is_lifetime => is_lifetime = false,
+            TokenTree::Ident(ident) if matches!(&*ident.to_string(), "mut" | "impl" | "dyn") => {}
+            TokenTree::Ident(ident) => {
+                let name = ident.to_string();
+                return is_closure_trait(&name) || callables.contains(&name);
+            }
+            _ => return false,
+        }
+    }
+
+    false
+}
+
 fn map_function_body(params: &mut FunctionParams, body: &proc_macro::Group) {
-    let body = map_statements(body);
-    params.function_statements = Some(body);
+    for callable in &params.callables {
+        declare_callable(callable);
+    }
+
+    params.function_statements = Some(map_statements(body));
 }
 
 fn build_contracts(attrs: TokenStream) -> String {
@@ -95,7 +309,7 @@ fn build_contracts(attrs: TokenStream) -> String {
     while let Some(tag) = iterator.next().map(|t| t.to_string()) {
         iterator
             .try_get_next_token(":")
-            .expect(&format!("expected a :, got {:?}", iterator.peek()));
+            .unwrap_or_else(|| panic!("expected a :, got {:?}", iterator.peek()));
 
         match iterator.next() {
             Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
@@ -118,7 +332,7 @@ fn build_contract(tag: String, mut iterator: TokenIterator) -> String {
     while let Some(condition) = iterator.next().map(|t| t.to_string()) {
         iterator
             .try_get_next_token(":")
-            .expect(&format!("expected a :, got {:?}", iterator.peek()));
+            .unwrap_or_else(|| panic!("expected a :, got {:?}", iterator.peek()));
 
         match iterator.next() {
             Some(TokenTree::Ident(function)) => {
@@ -0,0 +1,138 @@
+use proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+
+use crate::{
+    expression_mapper::try_get_identifier,
+    function_mapper::{map_function_builder, map_generics},
+    token_iterator::TokenIterator,
+};
+
+/// Defines a builder for every method of an `impl` block, along with the
+/// `__method_builder` function the interpreter looks them up with when calling a method
+/// on a value of the type.
+pub(crate) fn define_method_builders(stream: TokenStream) -> TokenStream {
+    let trees = stream.clone().into_iter().collect::<Vec<_>>();
+
+    let body_index = trees
+        .iter()
+        .position(
+            |tree| matches!(tree, TokenTree::Group(group) if group.delimiter() == Delimiter::Brace),
+        )
+        .expect("expected the body of the impl block");
+
+    let header = &trees[..body_index];
+    let (type_name, generics) = map_self_type(header);
+
+    let body = match &trees[body_index] {
+        TokenTree::Group(group) => group.stream().into_iter().collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+
+    let mut builders = String::new();
+    let mut methods = Vec::new();
+
+    for method in split_methods(&body) {
+        builders += &map_function_builder(method, "", Some((&type_name, &generics)));
+        methods.push(method_name(method));
+    }
+
+    let resolver_arms = methods
+        .iter()
+        .map(|method| {
+            format!(
+                "\"{}\" => Some(Self::{}__function_builder),",
+                method, method
+            )
+        })
+        .collect::<String>();
+
+    let builders: TokenStream = format!(
+        r###"
+        {}
+
+        #[doc(hidden)]
+        pub fn __method_builder(method: &str) -> Option<algorithmify::expressions::FunctionBuilder> {{
+            match method {{
+                {}
+                _ => None,
+            }}
+        }}
+    "###,
+        builders, resolver_arms
+    )
+    .parse()
+    .unwrap();
+
+    // the builders go in an `impl` block of their own, with the same header as the
+    // original one
+    let builder_stream = "#[allow(dead_code)]"
+        .parse::<TokenStream>()
+        .unwrap()
+        .into_iter()
+        .chain(header.iter().cloned())
+        .chain([TokenTree::Group(Group::new(Delimiter::Brace, builders))])
+        .chain("#[allow(unused_labels)]".parse::<TokenStream>().unwrap())
+        .collect::<TokenStream>();
+
+    builder_stream.into_iter().chain(stream).collect()
+}
+
+/// Reads the name of the type of an `impl` block and the names of its generic
+/// parameters.
+fn map_self_type(header: &[TokenTree]) -> (String, Vec<String>) {
+    let mut iterator: TokenIterator = header.into();
+
+    iterator.try_get_next_token("unsafe");
+    iterator
+        .try_get_next_token("impl")
+        .expect("expected an impl block");
+
+    let generics = map_generics(&mut iterator, &mut Vec::new());
+
+    let mut name = try_get_identifier(&mut iterator).expect("expected the type of the impl block");
+    while iterator.try_get_next_token(":").is_some() {
+        iterator.try_get_next_token(":");
+        name = try_get_identifier(&mut iterator).expect("expected the type of the impl block");
+    }
+
+    if header.iter().any(|tree| tree.to_string() == "for") {
+        panic!("define_method_builders only supports inherent impl blocks");
+    }
+
+    (name, generics)
+}
+
+/// Splits the body of an `impl` block into its methods, along with their attributes and
+/// visibility. Other items, like associated constants, are skipped.
+fn split_methods(body: &[TokenTree]) -> Vec<&[TokenTree]> {
+    let mut methods = Vec::new();
+    let mut item_start = 0;
+
+    for (index, tree) in body.iter().enumerate() {
+        match tree {
+            TokenTree::Punct(punctuation) if punctuation.as_char() == ';' => {
+                item_start = index + 1;
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                let item = &body[item_start..=index];
+
+                if item.iter().any(|tree| tree.to_string() == "fn") {
+                    methods.push(item);
+                }
+
+                item_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    methods
+}
+
+fn method_name(method: &[TokenTree]) -> String {
+    let position = method
+        .iter()
+        .position(|tree| tree.to_string() == "fn")
+        .expect("expected a method");
+
+    method[position + 1].to_string()
+}
@@ -1,14 +1,32 @@
 use proc_macro::TokenStream;
 
+mod closure_mapper;
 mod condition_mapper;
+mod control_flow_mapper;
+mod derive_mapper;
 mod expression_mapper;
+mod format_mapper;
 mod function_mapper;
+mod impl_mapper;
 mod loop_mapper;
+mod pattern_mapper;
+mod scope;
 mod statement_mapper;
 mod token_container;
 mod token_iterator;
+mod type_mapper;
 
 #[proc_macro_attribute]
 pub fn define_function_builder(attrs: TokenStream, stream: TokenStream) -> TokenStream {
     function_mapper::define_function_builder(stream, attrs)
 }
+
+#[proc_macro_attribute]
+pub fn define_method_builders(_attrs: TokenStream, stream: TokenStream) -> TokenStream {
+    impl_mapper::define_method_builders(stream)
+}
+
+#[proc_macro_derive(Expression)]
+pub fn derive_expression(stream: TokenStream) -> TokenStream {
+    derive_mapper::derive_expression(stream)
+}
@@ -1,7 +1,10 @@
-use proc_macro::TokenTree;
+use proc_macro::{Delimiter, TokenTree};
 
 use crate::{
-    expression_mapper::{map_expression, map_reference, map_statements, ExpressionMapping},
+    expression_mapper::{
+        map_expression, map_range, map_reference, map_statements, ExpressionMapping,
+    },
+    pattern_mapper::map_pattern,
     token_iterator::TokenIterator,
 };
 
@@ -10,6 +13,26 @@ pub(crate) fn map_for_loop(iterator: &mut TokenIterator) -> Option<ExpressionMap
 
     iterator.try_get_next_token("for")?;
 
+    let index = iterator.index;
+
+    // loops over a range are run without building the whole list of values
+    let for_loop = map_ranged_for_loop(iterator, &tag).or_else(|| {
+        iterator.rewind_to(index);
+        map_iterator_for_loop(iterator, &tag)
+    })?;
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Loop(Box::new({})),",
+        for_loop
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: false,
+    })
+}
+
+fn map_ranged_for_loop(iterator: &mut TokenIterator, tag: &str) -> Option<String> {
     iterator.try_get_next_token("mut");
 
     let variable = if let Some(TokenTree::Ident(variable)) = iterator.next() {
@@ -20,12 +43,53 @@ pub(crate) fn map_for_loop(iterator: &mut TokenIterator) -> Option<ExpressionMap
 
     iterator.try_get_next_token("in")?;
 
-    let start = map_expression(iterator)?.mapping;
+    let range = map_range(iterator)?;
+
+    let statements = match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => map_statements(group),
+        _ => return None,
+    };
+
+    Some(format!(
+        "algorithmify::expressions::loops::Loop::RangedFor(algorithmify::expressions::loops::RangedForLoop {{
+            tag: {},
+            statements: vec![{}],
+            variable: {},
+            range: {}
+        }})",
+        tag, statements, variable, range
+    ))
+}
+
+fn map_iterator_for_loop(iterator: &mut TokenIterator, tag: &str) -> Option<String> {
+    let pattern = map_pattern(iterator)?;
+
+    iterator.try_get_next_token("in")?;
+
+    let expression = map_expression(iterator)?.mapping;
 
-    iterator.try_get_next_token(".")?;
-    iterator.try_get_next_token(".")?;
+    let statements = match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => map_statements(group),
+        _ => return None,
+    };
 
-    let end = map_expression(iterator)?.mapping;
+    Some(format!(
+        "algorithmify::expressions::loops::Loop::IteratorFor(algorithmify::expressions::loops::IteratorForLoop {{
+            tag: {},
+            statements: vec![{}],
+            pattern: {},
+            iterator: {}
+        }})",
+        tag, statements, pattern, expression
+    ))
+}
+
+pub(crate) fn map_while_loop(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let tag = try_get_tag(iterator);
+
+    iterator.try_get_next_token("while")?;
+
+    let condition = map_expression(iterator)?.mapping;
 
     let statements = if let TokenTree::Group(group) = iterator.next()? {
         map_statements(group)
@@ -33,20 +97,18 @@ pub(crate) fn map_for_loop(iterator: &mut TokenIterator) -> Option<ExpressionMap
         return None;
     };
 
-    let for_loop = format!(
-        "algorithmify::expressions::loops::RangedForLoop {{
+    let while_loop = format!(
+        "algorithmify::expressions::loops::WhileLoop {{
             tag: {},
             statements: vec![{}],
-            variable: {},
-            start: {},
-            end: {}
+            condition: {},
         }}",
-        tag, statements, variable, start, end
+        tag, statements, condition
     );
 
     let mapping=  format!(
-        "algorithmify::expressions::Expression::Loop(Box::new(algorithmify::expressions::loops::Loop::RangedFor({}))),",
-        for_loop
+        "algorithmify::expressions::Expression::Loop(Box::new(algorithmify::expressions::loops::Loop::While({}))),",
+        while_loop
     );
 
     Some(ExpressionMapping {
@@ -55,12 +117,17 @@ pub(crate) fn map_for_loop(iterator: &mut TokenIterator) -> Option<ExpressionMap
     })
 }
 
-pub(crate) fn map_while_loop(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+pub(crate) fn map_while_let_loop(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
     let tag = try_get_tag(iterator);
 
     iterator.try_get_next_token("while")?;
+    iterator.try_get_next_token("let")?;
 
-    let condition = map_expression(iterator)?.mapping;
+    let pattern = map_pattern(iterator)?;
+
+    iterator.try_get_next_token("=")?;
+
+    let expression = map_expression(iterator)?.mapping;
 
     let statements = if let TokenTree::Group(group) = iterator.next()? {
         map_statements(group)
@@ -68,18 +135,49 @@ pub(crate) fn map_while_loop(iterator: &mut TokenIterator) -> Option<ExpressionM
         return None;
     };
 
-    let while_loop = format!(
-        "algorithmify::expressions::loops::WhileLoop {{
+    let while_let_loop = format!(
+        "algorithmify::expressions::loops::WhileLetLoop {{
             tag: {},
             statements: vec![{}],
-            condition: {},
+            pattern: {},
+            expression: {},
         }}",
-        tag, statements, condition
+        tag, statements, pattern, expression
     );
 
-    let mapping=  format!(
-        "algorithmify::expressions::Expression::Loop(Box::new(algorithmify::expressions::loops::Loop::While({}))),",
-        while_loop
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Loop(Box::new(algorithmify::expressions::loops::Loop::WhileLet({})))",
+        while_let_loop
+    );
+
+    Some(ExpressionMapping {
+        mapping,
+        needs_semicolon_unless_final: false,
+    })
+}
+
+pub(crate) fn map_infinite_loop(iterator: &mut TokenIterator) -> Option<ExpressionMapping> {
+    let tag = try_get_tag(iterator);
+
+    iterator.try_get_next_token("loop")?;
+
+    let statements = if let TokenTree::Group(group) = iterator.next()? {
+        map_statements(group)
+    } else {
+        return None;
+    };
+
+    let infinite_loop = format!(
+        "algorithmify::expressions::loops::InfiniteLoop {{
+            tag: {},
+            statements: vec![{}],
+        }}",
+        tag, statements
+    );
+
+    let mapping = format!(
+        "algorithmify::expressions::Expression::Loop(Box::new(algorithmify::expressions::loops::Loop::Infinite({})))",
+        infinite_loop
     );
 
     Some(ExpressionMapping {
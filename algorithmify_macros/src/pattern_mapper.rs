@@ -0,0 +1,185 @@
+use proc_macro::{Delimiter, TokenTree};
+
+use crate::{
+    expression_mapper::{
+        alt, map_integer, map_native_path, try_get_enum_variant_path, try_get_identifier,
+    },
+    token_iterator::TokenIterator,
+};
+
+pub(crate) fn map_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    iterator.try_get_next_token("|");
+
+    let mut patterns = vec![map_single_pattern(iterator)?];
+
+    while iterator.try_get_next_token("|").is_some() {
+        patterns.push(map_single_pattern(iterator)?);
+    }
+
+    if patterns.len() == 1 {
+        patterns.pop()
+    } else {
+        Some(format!(
+            "algorithmify::expressions::Pattern::Or(vec![{}])",
+            patterns.join(",")
+        ))
+    }
+}
+
+pub(crate) fn map_single_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    alt(
+        iterator,
+        &[
+            map_wildcard_pattern,
+            map_tuple_pattern,
+            map_variant_pattern,
+            map_enum_pattern,
+            map_range_pattern,
+            map_literal_pattern,
+            map_binding_pattern,
+        ],
+    )
+}
+
+fn map_wildcard_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    iterator.try_get_next_token("_")?;
+    Some("algorithmify::expressions::Pattern::Wildcard".to_owned())
+}
+
+pub(crate) fn map_tuple_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+            let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+            let mut patterns = Vec::new();
+
+            while iterator.peek().is_some() {
+                patterns.push(map_pattern(&mut iterator)?);
+
+                if iterator.try_get_next_token(",").is_none() && iterator.peek().is_some() {
+                    return None;
+                }
+            }
+
+            Some(format!(
+                "algorithmify::expressions::Pattern::Tuple(vec![{}])",
+                patterns.join(",")
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn map_variant_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    let variant = match &*try_get_identifier(iterator)? {
+        "None" => return Some("algorithmify::expressions::Pattern::None".to_owned()),
+        variant @ ("Some" | "Ok" | "Err") => variant.to_owned(),
+        _ => return None,
+    };
+
+    match iterator.next()? {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+            let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+            let pattern = map_pattern(&mut iterator)?;
+
+            if iterator.next().is_some() {
+                return None;
+            }
+
+            Some(format!(
+                "algorithmify::expressions::Pattern::{}(Box::new({}))",
+                variant, pattern
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn map_enum_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    let (path, name, variant) = try_get_enum_variant_path(iterator)?;
+
+    let index = iterator.index;
+    let patterns = match iterator.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            let mut iterator: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+            let mut patterns = Vec::new();
+
+            while iterator.peek().is_some() {
+                patterns.push(map_pattern(&mut iterator)?);
+
+                if iterator.try_get_next_token(",").is_none() && iterator.peek().is_some() {
+                    return None;
+                }
+            }
+
+            patterns
+        }
+        // like in expressions, a path without a payload can be an associated constant
+        _ => {
+            iterator.rewind_to(index);
+            return Some(format!(
+                "algorithmify::expressions::Pattern::Literal({})",
+                map_native_path(&path)
+            ));
+        }
+    };
+
+    Some(format!(
+        "algorithmify::expressions::Pattern::Enum(\"{}\".to_owned(), \"{}\".to_owned(), vec![{}])",
+        name,
+        variant,
+        patterns.join(",")
+    ))
+}
+
+fn map_range_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    let start = map_literal(iterator)?;
+
+    iterator.try_get_next_token(".")?;
+    iterator.try_get_next_token(".")?;
+    iterator.try_get_next_token("=")?;
+
+    let end = map_literal(iterator)?;
+
+    Some(format!(
+        "algorithmify::expressions::Pattern::Range({}, {})",
+        start, end
+    ))
+}
+
+fn map_literal_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    let literal = map_literal(iterator)?;
+    Some(format!(
+        "algorithmify::expressions::Pattern::Literal({})",
+        literal
+    ))
+}
+
+fn map_binding_pattern(iterator: &mut TokenIterator) -> Option<String> {
+    iterator.try_get_next_token("mut");
+
+    match iterator.next()? {
+        TokenTree::Ident(identifier) => Some(format!(
+            "algorithmify::expressions::Pattern::Binding(\"{}\".to_owned())",
+            identifier
+        )),
+        _ => None,
+    }
+}
+
+fn map_literal(iterator: &mut TokenIterator) -> Option<String> {
+    let negative = iterator.try_get_next_token("-").is_some();
+
+    match iterator.next()? {
+        TokenTree::Ident(identifier) if !negative => match &*identifier.to_string() {
+            "true" => Some("algorithmify::expressions::Expression::Bool(true)".to_owned()),
+            "false" => Some("algorithmify::expressions::Expression::Bool(false)".to_owned()),
+            _ => None,
+        },
+        TokenTree::Literal(literal) if literal.to_string().starts_with('\'') && !negative => Some(
+            format!("algorithmify::expressions::Expression::Char({})", literal),
+        ),
+        TokenTree::Literal(literal) if negative => map_integer(&format!("-{}", literal)),
+        TokenTree::Literal(literal) => map_integer(&literal.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,49 @@
+use std::{cell::RefCell, collections::BTreeSet};
+
+/// What's known about the function being mapped that isn't visible from the tokens
+/// of a single expression.
+#[derive(Default)]
+pub(crate) struct Scope {
+    /// The local variables that hold closures, whose calls have to be interpreted as
+    /// closure calls instead of function calls.
+    pub(crate) callables: BTreeSet<String>,
+    /// The name of the type of the `impl` block the function belongs to, if any.
+    pub(crate) self_type: Option<String>,
+}
+
+thread_local! {
+    static SCOPE: RefCell<Scope> = RefCell::new(Scope::default());
+}
+
+/// Maps a function body within the given scope.
+pub(crate) fn with_scope<T>(scope: Scope, map: impl FnOnce() -> T) -> T {
+    let previous = SCOPE.with(|current| current.replace(scope));
+    let result = map();
+    SCOPE.with(|current| current.replace(previous));
+    result
+}
+
+/// Maps a block, at the end of which the closures bound within it go out of scope.
+pub(crate) fn with_block_scope<T>(map: impl FnOnce() -> T) -> T {
+    let callables = SCOPE.with(|scope| scope.borrow().callables.clone());
+    let result = map();
+    SCOPE.with(|scope| scope.borrow_mut().callables = callables);
+    result
+}
+
+pub(crate) fn declare_callable(name: &str) {
+    SCOPE.with(|scope| scope.borrow_mut().callables.insert(name.to_owned()));
+}
+
+/// Forgets a closure shadowed by a variable that holds some other value.
+pub(crate) fn undeclare_callable(name: &str) {
+    SCOPE.with(|scope| scope.borrow_mut().callables.remove(name));
+}
+
+pub(crate) fn is_callable(name: &str) -> bool {
+    SCOPE.with(|scope| scope.borrow().callables.contains(name))
+}
+
+pub(crate) fn self_type() -> Option<String> {
+    SCOPE.with(|scope| scope.borrow().self_type.clone())
+}
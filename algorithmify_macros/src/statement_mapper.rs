@@ -1,8 +1,14 @@
 use proc_macro::TokenTree;
 
 use crate::{
-    expression_mapper::{map_expression, map_reference, try_get_indexed_access},
+    closure_mapper::is_closure_ahead,
+    expression_mapper::{
+        map_expression, map_place, map_reference, map_term, next_operator, try_get_identifier,
+    },
+    pattern_mapper::map_tuple_pattern,
+    scope::{declare_callable, undeclare_callable},
     token_iterator::TokenIterator,
+    type_mapper::map_type,
 };
 
 #[derive(Debug)]
@@ -12,8 +18,11 @@ pub(crate) struct StatementMapping {
 
 pub(crate) fn map_statement(buffer: &mut String, iterator: &mut TokenIterator) {
     let result = [
+        try_map_assertion,
         try_map_assignment,
-        try_map_indexed_assignment,
+        try_map_place_assignment,
+        try_map_destructuring,
+        try_map_compound_assignment,
         try_map_expression,
     ]
     .iter()
@@ -43,37 +52,96 @@ fn execute_assignment_mapping(
     if result.is_none() {
         iterator.rewind_to(index);
     }
-    return result;
+    result
 }
 
 fn try_map_assignment(iterator: &mut TokenIterator) -> Option<StatementMapping> {
-    iterator.try_get_next_token("let");
+    let is_declaration = iterator.try_get_next_token("let").is_some();
     iterator.try_get_next_token("mut");
 
-    let identifier = if let TokenTree::Ident(ident) = iterator.next()? {
-        map_reference(ident)
+    // writing through a `&mut` parameter or binding, as in `*x = 0`, assigns the place
+    // it borrows, or the parameter itself, whose final value is handed back to the caller
+    let mut is_dereference = false;
+    if !is_declaration {
+        while iterator.try_get_next_token("*").is_some() {
+            is_dereference = true;
+        }
+    }
+
+    let (name, identifier) = if let TokenTree::Ident(ident) = iterator.next()? {
+        (ident.to_string(), map_reference(ident))
     } else {
         return None;
     };
 
+    let value_type = if is_declaration && iterator.try_get_next_token(":").is_some() {
+        Some(map_type(iterator)?)
+    } else {
+        None
+    };
+
+    iterator.try_get_next_token("=")?;
+
+    let index = iterator.index;
+    let is_borrow = is_declaration
+        && iterator.try_get_next_token("&").is_some()
+        && iterator.try_get_next_token("mut").is_some();
+    iterator.rewind_to(index);
+
+    // calls to variables bound to closures are closure calls from then on, until the
+    // end of the block or until they are shadowed
+    if is_closure_ahead(iterator) {
+        declare_callable(&name);
+    } else if is_declaration {
+        undeclare_callable(&name);
+    }
+
+    let expression = map_expression(iterator)?;
+
+    iterator.try_get_next_token(";")?;
+
+    let mapping = match value_type {
+        _ if is_borrow => format!(
+            "algorithmify::expressions::Statement::Borrow({}, {})",
+            identifier, expression.mapping
+        ),
+        _ if is_dereference => format!(
+            "algorithmify::expressions::Statement::PlaceAssignment(algorithmify::expressions::Expression::Reference({}), {})",
+            identifier, expression.mapping
+        ),
+        Some(value_type) => format!(
+            "algorithmify::expressions::Statement::TypedAssignment({}, {}, {})",
+            identifier, value_type, expression.mapping
+        ),
+        None => format!(
+            "algorithmify::expressions::Statement::Assignment({}, {})",
+            identifier, expression.mapping
+        ),
+    };
+
+    Some(StatementMapping { mapping })
+}
+
+fn try_map_place_assignment(iterator: &mut TokenIterator) -> Option<StatementMapping> {
+    let place = map_place(iterator)?;
+
     iterator.try_get_next_token("=")?;
     let expression = map_expression(iterator)?;
 
     iterator.try_get_next_token(";")?;
 
     let mapping = format!(
-        "algorithmify::expressions::Statement::Assignment({}, {})",
-        identifier, expression.mapping
+        "algorithmify::expressions::Statement::PlaceAssignment({}, {})",
+        place, expression.mapping
     );
 
     Some(StatementMapping { mapping })
 }
 
-fn try_map_indexed_assignment(iterator: &mut TokenIterator) -> Option<StatementMapping> {
+fn try_map_destructuring(iterator: &mut TokenIterator) -> Option<StatementMapping> {
     iterator.try_get_next_token("let");
-    iterator.try_get_next_token("mut");
 
-    let identifier = try_get_indexed_access(iterator)?;
+    let pattern = map_tuple_pattern(iterator)?;
 
     iterator.try_get_next_token("=")?;
     let expression = map_expression(iterator)?;
@@ -81,8 +149,82 @@ fn try_map_indexed_assignment(iterator: &mut TokenIterator) -> Option<StatementM
     iterator.try_get_next_token(";")?;
 
     let mapping = format!(
-        "algorithmify::expressions::Statement::IndexedAssigment({}, {})",
-        identifier, expression.mapping
+        "algorithmify::expressions::Statement::Destructuring({}, {})",
+        pattern, expression.mapping
+    );
+
+    Some(StatementMapping { mapping })
+}
+
+/// Maps the `assert!` family of macros, whose source text is kept for the error of a
+/// failed assertion. Messages after the asserted values are only part of that text.
+fn try_map_assertion(iterator: &mut TokenIterator) -> Option<StatementMapping> {
+    let name = try_get_identifier(iterator)?;
+
+    let kind = match &*name {
+        "assert" | "debug_assert" => "Condition",
+        "assert_eq" | "debug_assert_eq" => "Eq",
+        "assert_ne" | "debug_assert_ne" => "Ne",
+        _ => return None,
+    };
+
+    iterator.try_get_next_token("!")?;
+
+    let group = match iterator.next()? {
+        TokenTree::Group(group) => group.clone(),
+        _ => return None,
+    };
+
+    iterator.try_get_next_token(";");
+
+    let text = format!(
+        "{}!{}",
+        name,
+        group.span().source_text().unwrap_or(group.to_string())
+    );
+
+    let mut inner: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+    let mut operands = vec![map_expression(&mut inner)?.mapping];
+
+    if kind != "Condition" {
+        inner.try_get_next_token(",")?;
+        operands.push(map_expression(&mut inner)?.mapping);
+    }
+
+    let mapping = format!(
+        "algorithmify::expressions::Statement::Assertion(algorithmify::expressions::Assertion::{}({:?}.to_owned(), {}))",
+        kind,
+        text,
+        operands.join(", ")
+    );
+
+    Some(StatementMapping { mapping })
+}
+
+fn try_map_compound_assignment(iterator: &mut TokenIterator) -> Option<StatementMapping> {
+    let place = map_term(iterator)?;
+
+    let operation = match &*next_operator(iterator)? {
+        "+=" => "Add",
+        "-=" => "Sub",
+        "*=" => "Mul",
+        "/=" => "Div",
+        "%=" => "Rem",
+        "&=" => "BitAnd",
+        "|=" => "BitOr",
+        "^=" => "BitXor",
+        "<<=" => "Shl",
+        ">>=" => "Shr",
+        _ => return None,
+    };
+
+    let expression = map_expression(iterator)?;
+
+    iterator.try_get_next_token(";")?;
+
+    let mapping = format!(
+        "algorithmify::expressions::Statement::CompoundAssignment(algorithmify::expressions::Operation::{}({}, {}))",
+        operation, place, expression.mapping
     );
 
     Some(StatementMapping { mapping })
@@ -90,7 +232,7 @@ fn try_map_indexed_assignment(iterator: &mut TokenIterator) -> Option<StatementM
 
 pub(crate) fn try_map_expression(iterator: &mut TokenIterator) -> Option<StatementMapping> {
     if let Some(expression) = map_expression(iterator) {
-        if let (Some(_), Some(_), _) | (None, None, _) | (None, Some(_), false) = (
+        if let (Some(_), _, _) | (None, None, _) | (None, Some(_), false) = (
             iterator.try_get_next_token(";"),
             iterator.peek(),
             expression.needs_semicolon_unless_final,
@@ -58,6 +58,10 @@ impl<'a> TokenIterator<'a> {
         }
     }
 
+    pub(crate) fn tokens_between(&self, start: usize, end: usize) -> &[TokenTree] {
+        &self.tokens[start..end]
+    }
+
     pub(crate) fn next_nth(&mut self, count: usize) -> Option<&[TokenTree]> {
         if self.index < self.tokens.len() {
             let end_index = if self.index + count < self.tokens.len() {
@@ -72,16 +76,4 @@ impl<'a> TokenIterator<'a> {
             None
         }
     }
-
-    pub(crate) fn next_nth_string(&mut self, count: usize) -> Option<String> {
-        if self.index + count >= self.tokens.len() {
-            None
-        } else {
-            let result = (self.index..self.index + count).fold(String::new(), |acc, index| {
-                acc + &self.tokens[index].to_string()
-            });
-            self.index += count;
-            Some(result)
-        }
-    }
 }
@@ -0,0 +1,192 @@
+use proc_macro::{Delimiter, TokenTree};
+
+use crate::{
+    expression_mapper::try_get_identifier, scope::self_type, token_iterator::TokenIterator,
+};
+
+/// Maps a type like `&mut Vec<(usize, T)>` into an `algorithmify::expressions::Type`.
+/// Shared references are dropped, since the interpreter works on values, while `&mut`
+/// ones are kept so that the final value can be handed back to the caller.
+pub(crate) fn map_type(iterator: &mut TokenIterator) -> Option<String> {
+    if iterator.try_get_next_token("&").is_some() {
+        try_skip_lifetime(iterator);
+
+        if iterator.try_get_next_token("mut").is_some() {
+            return Some(format!(
+                "algorithmify::expressions::Type::MutableReference(Box::new({}))",
+                map_type(iterator)?
+            ));
+        }
+    }
+
+    if let Some(TokenTree::Group(group)) = iterator.peek().cloned() {
+        iterator.next();
+
+        let mut inner: TokenIterator = group.stream().into_iter().collect::<Vec<_>>().into();
+
+        return match group.delimiter() {
+            // slices and arrays are both vectors to the interpreter
+            Delimiter::Bracket => Some(format!(
+                "algorithmify::expressions::Type::Vector(Box::new({}))",
+                map_type(&mut inner)?
+            )),
+            Delimiter::Parenthesis if group.stream().is_empty() => {
+                Some("algorithmify::expressions::Type::Unit".to_owned())
+            }
+            Delimiter::Parenthesis => {
+                let types = map_type_list(&mut inner)?;
+                let is_tuple = matches!(
+                    group.stream().into_iter().last(),
+                    Some(TokenTree::Punct(punctuation)) if punctuation.as_char() == ','
+                );
+
+                if types.len() == 1 && !is_tuple {
+                    types.into_iter().next()
+                } else {
+                    Some(format!(
+                        "algorithmify::expressions::Type::Tuple(vec![{}])",
+                        types.join(",")
+                    ))
+                }
+            }
+            _ => None,
+        };
+    }
+
+    // `impl Fn(T)` and `dyn Fn(T)` are just closures to the interpreter
+    if iterator.try_get_next_token("impl").is_none() {
+        iterator.try_get_next_token("dyn");
+    }
+
+    let mut name = try_get_identifier(iterator)?;
+    while iterator.try_get_next_token(":").is_some() {
+        iterator.try_get_next_token(":")?;
+        name = try_get_identifier(iterator)?;
+    }
+
+    // within an `impl` block, `Self` is the type of the block
+    if name == "Self" {
+        name = self_type().unwrap_or(name);
+    }
+
+    if let ("Fn" | "FnMut" | "FnOnce", Some(TokenTree::Group(params))) =
+        (&*name, iterator.peek().cloned())
+    {
+        iterator.next();
+        return map_function_type(iterator, &params);
+    }
+
+    let arguments = if iterator.try_get_next_token("<").is_some() {
+        let mut arguments = Vec::new();
+
+        while iterator.try_get_next_token(">").is_none() {
+            if !try_skip_lifetime(iterator) {
+                arguments.push(map_type(iterator)?);
+            }
+            iterator.try_get_next_token(",");
+        }
+
+        arguments
+    } else {
+        Vec::new()
+    };
+
+    let primitive = match &*name {
+        "i8" => "I8",
+        "i16" => "I16",
+        "i32" => "I32",
+        "i64" => "I64",
+        "isize" => "Isize",
+        "u8" => "U8",
+        "u16" => "U16",
+        "u32" => "U32",
+        "u64" => "U64",
+        "usize" => "Usize",
+        "f32" => "F32",
+        "f64" => "F64",
+        "bool" => "Bool",
+        "char" => "Char",
+        "str" | "String" => "String",
+        _ => "",
+    };
+
+    let mapping = match (&*name, arguments.as_slice()) {
+        (_, []) if !primitive.is_empty() => {
+            format!("algorithmify::expressions::Type::{}", primitive)
+        }
+        ("Vec", [element]) => {
+            format!(
+                "algorithmify::expressions::Type::Vector(Box::new({}))",
+                element
+            )
+        }
+        ("Option", [value]) => {
+            format!(
+                "algorithmify::expressions::Type::Option(Box::new({}))",
+                value
+            )
+        }
+        ("Result", [value, error]) => format!(
+            "algorithmify::expressions::Type::Result(Box::new({}), Box::new({}))",
+            value, error
+        ),
+        ("Box", [value]) => value.clone(),
+        (name, arguments) => format!(
+            "algorithmify::expressions::Type::Named(\"{}\".to_owned(), vec![{}])",
+            name,
+            arguments.join(",")
+        ),
+    };
+
+    Some(mapping)
+}
+
+fn map_function_type(iterator: &mut TokenIterator, params: &proc_macro::Group) -> Option<String> {
+    let mut inner: TokenIterator = params.stream().into_iter().collect::<Vec<_>>().into();
+    let params = map_type_list(&mut inner)?;
+
+    let return_type = if iterator.try_get_next_token("-").is_some() {
+        iterator.try_get_next_token(">")?;
+        map_type(iterator)?
+    } else {
+        "algorithmify::expressions::Type::Unit".to_owned()
+    };
+
+    // additional bounds like `+ 'a` or `+ Copy` don't matter to the interpreter
+    while iterator.try_get_next_token("+").is_some() {
+        if !try_skip_lifetime(iterator) {
+            try_get_identifier(iterator)?;
+        }
+    }
+
+    Some(format!(
+        "algorithmify::expressions::Type::Function(vec![{}], Box::new({}))",
+        params.join(","),
+        return_type
+    ))
+}
+
+fn map_type_list(iterator: &mut TokenIterator) -> Option<Vec<String>> {
+    let mut types = Vec::new();
+
+    while iterator.peek().is_some() {
+        types.push(map_type(iterator)?);
+
+        if iterator.try_get_next_token(",").is_none() && iterator.peek().is_some() {
+            return None;
+        }
+    }
+
+    Some(types)
+}
+
+fn try_skip_lifetime(iterator: &mut TokenIterator) -> bool {
+    let index = iterator.index;
+
+    if iterator.try_get_next_token("'").is_some() && try_get_identifier(iterator).is_some() {
+        return true;
+    }
+
+    iterator.rewind_to(index);
+    false
+}